@@ -3,12 +3,19 @@ mod solver;
 
 use advent_of_code::{
     env::project::Project,
-    puzzles::puzzle::{Puzzle, PuzzleResult},
+    puzzles::{
+        puzzle::{Puzzle, PuzzleResult},
+        puzzle_answers::PuzzleAnswers,
+    },
 };
 use solver::Solver;
 
 fn main() -> PuzzleResult {
-    let input_file = Project::new().project_file(&["examples", "resources"], "numbers.txt");
+    let project = Project::new();
+    let input_file = project.project_file(&["examples", "resources"], "numbers.txt");
+    let answers_file = project.project_file(&["examples", "resources"], "numbers_answers.txt");
+
     let mut puzzle = Puzzle::<Solver>::new_with_reader(&input_file);
-    puzzle.solve()
+    puzzle.set_answers(Some(PuzzleAnswers::load(&answers_file)?));
+    puzzle.solve()?.into_result()
 }
@@ -1,20 +1,14 @@
-use advent_of_code::puzzles::puzzle_error::PuzzleError;
+use advent_of_code::puzzles::{input_line::InputLine, puzzle_error::PuzzleError};
 
 pub struct Parser {}
 
 impl Parser {
     pub fn parse_lines(lines: &[&str]) -> Result<Vec<u32>, PuzzleError> {
-        lines.iter().map(|line| Self::parse_line(line)).collect()
+        InputLine::iter(lines).map(Self::parse_line).collect()
     }
 
-    fn parse_line(line: &str) -> Result<u32, PuzzleError> {
-        // Try to convert line to u32 number
-        let number = line.parse::<u32>().map_err(|err| {
-            PuzzleError::InvalidContentError(format!(
-                "Failed to convert '{line}' to u32 with error '{err}'"
-            ))
-        })?;
-
-        Ok(number)
+    fn parse_line(line: InputLine) -> Result<u32, PuzzleError> {
+        // Try to convert line to u32 number, error points to the line of the input file
+        line.parse::<u32>()
     }
 }
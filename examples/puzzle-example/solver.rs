@@ -3,6 +3,7 @@ use std::{thread::sleep, time::Duration};
 use advent_of_code::puzzles::{
     puzzle::{PuzzleResult, SolutionResult},
     puzzle_solver::PuzzleSolver,
+    solution_progress::SolutionProgress,
 };
 
 use crate::parser::Parser;
@@ -30,10 +31,19 @@ impl PuzzleSolver for Solver {
     fn part_1(&self) -> SolutionResult {
         // Simulate long running task to see progress bar
         sleep(Duration::from_secs(3));
-        Ok(self.numbers.iter().sum::<u32>().to_string())
+        Ok(self.numbers.iter().sum::<u32>().into())
     }
 
     fn part_2(&self) -> SolutionResult {
-        Ok(self.numbers.iter().product::<u32>().to_string())
+        // Simulate long running task which reports its progress
+        let progress = SolutionProgress::current();
+        progress.set_total(30);
+
+        for _ in 0..30 {
+            sleep(Duration::from_millis(100));
+            progress.inc(1);
+        }
+
+        Ok(self.numbers.iter().product::<u32>().into())
     }
 }
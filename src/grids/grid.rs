@@ -1,19 +1,26 @@
-use std::ops::{Index, IndexMut};
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
 
 use crate::puzzles::puzzle_error::PuzzleError;
 
-use super::{direction::Direction, point::Point};
+use super::{direction::Direction, point::Point, region::Region};
 
-#[derive(Default, Clone, PartialEq, Eq)]
-pub struct Grid {
+/// Two dimensional grid of cells. Cell type defaults to `char` which is the most common case
+/// when the grid is parsed directly from the puzzle input.
+///
+/// Cells are stored row by row in a single contiguous buffer.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Grid<T = char> {
     rows: usize,
     cols: usize,
-    internal: Vec<Vec<char>>,
+    internal: Vec<T>,
 }
 
-impl Grid {
+impl<T> Grid<T> {
     /// Create new grid, rows and columns count is deduced from input data
-    pub fn new(data: Vec<Vec<char>>) -> Result<Self, PuzzleError> {
+    pub fn new(data: Vec<Vec<T>>) -> Result<Self, PuzzleError> {
         // Check input data
         if data.is_empty() {
             return Err(PuzzleError::GenericError("Grid is empty".to_string()));
@@ -26,39 +33,33 @@ impl Grid {
         let rows = data.len();
         let cols = data[0].len();
 
+        // All rows have to be of the same length to be stored in a flat buffer
+        if let Some((i, row)) = data.iter().enumerate().find(|(_, row)| row.len() != cols) {
+            return Err(PuzzleError::GenericError(format!(
+                "Grid[{}] has {} columns, expected {}",
+                i,
+                row.len(),
+                cols
+            )));
+        }
+
         Ok(Self {
             rows,
             cols,
-            internal: data,
+            internal: data.into_iter().flatten().collect(),
         })
     }
 
-    pub fn new_from_lines(lines: &[&str]) -> Result<Self, PuzzleError> {
-        // Parse lines to Vec<Vec<char>>
-        let grid = lines
-            .iter()
-            .map(|line| line.chars().collect::<Vec<_>>())
-            .collect::<Vec<_>>();
-
-        Self::new(grid)
-    }
-
     pub fn new_with<F>(rows: usize, cols: usize, func: F) -> Result<Self, PuzzleError>
     where
-        F: Fn(Point) -> char,
+        F: Fn(Point) -> T,
     {
-        let internal = (0..rows)
-            .map(|i| {
-                {
-                    (0..cols)
-                        .map(|j| {
-                            func(Point {
-                                x: j as isize,
-                                y: i as isize,
-                            })
-                        })
-                        .collect::<Vec<_>>()
-                }
+        let internal = (0..rows * cols)
+            .map(|index| {
+                func(Point {
+                    x: (index % cols) as isize,
+                    y: (index / cols) as isize,
+                })
             })
             .collect::<Vec<_>>();
 
@@ -77,22 +78,160 @@ impl Grid {
         self.cols
     }
 
-    pub fn fill(&mut self, data: &[(Point, char)]) -> Result<(), PuzzleError> {
-        // Avoid changing of the grid if there is invalid point
-        for (point, _) in data {
-            if !self.is_point_in_grid(point) {
-                return Err(PuzzleError::GenericError(format!(
-                    "Point {:?} is not in the grid",
-                    point
-                )));
+    /// Convert grid to a grid with a different cell type
+    pub fn map<U, F>(&self, func: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            internal: self.internal.iter().map(func).collect(),
+        }
+    }
+
+    /// Convert grid to a grid with a different cell type, conversion has access to the cell position
+    pub fn map_with_point<U, F>(&self, func: F) -> Grid<U>
+    where
+        F: Fn(Point, &T) -> U,
+    {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            internal: self
+                .enumerate()
+                .map(|(point, value)| func(point, value))
+                .collect(),
+        }
+    }
+
+    /// Get value at the given point, `None` if point is outside of the grid
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.internal[index])
+    }
+
+    /// Get mutable value at the given point, `None` if point is outside of the grid
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.internal[index])
+    }
+
+    /// Get all cells of the row `y`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is out of range
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.rows, "Row {} is out of range 0..{}", y, self.rows);
+        &self.internal[y * self.cols..(y + 1) * self.cols]
+    }
+
+    /// Get all cells of the column `x` from top to bottom
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is out of range
+    pub fn col(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            x < self.cols,
+            "Column {} is out of range 0..{}",
+            x,
+            self.cols
+        );
+        self.internal.iter().skip(x).step_by(self.cols)
+    }
+
+    /// Iterate over all rows from top to bottom
+    pub fn rows_iter(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // Default grid has no columns, chunks of zero size are not allowed
+        self.internal.chunks(self.cols.max(1))
+    }
+
+    /// Iterate over all columns from left to right
+    pub fn cols_iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.cols).map(|x| self.col(x))
+    }
+
+    /// Iterate over all points of the grid row by row
+    pub fn iter_points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let cols = self.cols;
+
+        (0..self.rows * cols).map(move |index| Self::point_of(cols, index))
+    }
+
+    /// Iterate over all cells together with their positions row by row
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.internal
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (Self::point_of(self.cols, index), value))
+    }
+
+    /// Find all points connected to the start in [Direction::CARDINAL] directions.
+    ///
+    /// # Arguments
+    ///
+    /// _start_ - Start point
+    /// _can_fill_ - Closure deciding if the fill can continue from the first point to the second
+    /// (neighboring) point
+    ///
+    /// # Returns
+    ///
+    /// Region with all filled points, empty region if start is not in the grid
+    pub fn flood_fill<F>(&self, start: &Point, can_fill: F) -> Region
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        if !self.is_point_in_grid(start) {
+            return Region::default();
+        }
+
+        let mut visited = vec![false; self.internal.len()];
+        self.fill_from(start, &can_fill, &mut visited)
+    }
+
+    fn fill_from<F>(&self, start: &Point, can_fill: &F, visited: &mut [bool]) -> Region
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        let mut points = Vec::new();
+        let mut stack = vec![*start];
+
+        if let Some(index) = self.index_of(start) {
+            visited[index] = true;
+        }
+
+        while let Some(point) = stack.pop() {
+            points.push(point);
+
+            for (neighbor, _) in self.neighbors_if(&point, &Direction::CARDINAL, |neighbor, _| {
+                can_fill(&point, neighbor)
+            }) {
+                if let Some(index) = self.index_of(&neighbor)
+                    && !visited[index]
+                {
+                    visited[index] = true;
+                    stack.push(neighbor);
+                }
             }
         }
 
-        for (point, value) in data {
-            self[*point] = *value;
+        Region::new(points)
+    }
+
+    fn index_of(&self, point: &Point) -> Option<usize> {
+        match self.is_point_in_grid(point) {
+            true => Some(point.y as usize * self.cols + point.x as usize),
+            false => None,
         }
+    }
 
-        Ok(())
+    fn point_of(cols: usize, index: usize) -> Point {
+        Point {
+            x: (index % cols) as isize,
+            y: (index / cols) as isize,
+        }
     }
 
     pub fn is_point_in_grid(&self, point: &Point) -> bool {
@@ -143,54 +282,113 @@ impl Grid {
             .filter_map(|direction| self.neighbor_if(point, direction, func.clone()))
             .collect::<Vec<_>>()
     }
+}
+
+impl<T> Grid<T>
+where
+    T: Clone,
+{
+    pub fn fill(&mut self, data: &[(Point, T)]) -> Result<(), PuzzleError> {
+        // Avoid changing of the grid if there is invalid point
+        for (point, _) in data {
+            if !self.is_point_in_grid(point) {
+                return Err(PuzzleError::GenericError(format!(
+                    "Point {:?} is not in the grid",
+                    point
+                )));
+            }
+        }
+
+        for (point, value) in data {
+            self[*point] = value.clone();
+        }
+
+        Ok(())
+    }
+}
 
+impl<T> Grid<T>
+where
+    T: Copy + PartialEq,
+{
     pub fn get_if<F>(&self, func: F) -> Vec<Point>
     where
-        F: Copy + Fn(char) -> bool,
+        F: Copy + Fn(T) -> bool,
     {
-        self.internal
-            .iter()
-            .enumerate()
-            .flat_map(|(i, row)| {
-                row.iter()
-                    .enumerate()
-                    .filter_map(move |(j, c)| match func(*c) {
-                        true => Some(Point {
-                            x: j as isize,
-                            y: i as isize,
-                        }),
-                        false => None,
-                    })
+        self.enumerate()
+            .filter_map(|(point, c)| match func(*c) {
+                true => Some(point),
+                false => None,
             })
             .collect()
     }
 
+    /// Split the grid to regions of connected cells with equal values
+    pub fn regions(&self) -> Vec<Region> {
+        self.split_regions(|_| true, |from, to| self[*from] == self[*to])
+    }
+
+    /// Split cells satisfying the predicate to connected regions, cells not satisfying the
+    /// predicate do not belong to any region
+    pub fn regions_if<F>(&self, func: F) -> Vec<Region>
+    where
+        F: Copy + Fn(T) -> bool,
+    {
+        self.split_regions(|point| func(self[*point]), |_, to| func(self[*to]))
+    }
+
+    /// Label every cell with an index of the region (see [Grid::regions]) it belongs to
+    pub fn label_regions(&self) -> Grid<usize> {
+        let mut labels = self.map(|_| 0);
+
+        for (label, region) in self.regions().iter().enumerate() {
+            for point in region.points() {
+                labels[*point] = label;
+            }
+        }
+
+        labels
+    }
+
+    fn split_regions<M, F>(&self, is_member: M, can_fill: F) -> Vec<Region>
+    where
+        M: Fn(&Point) -> bool,
+        F: Fn(&Point, &Point) -> bool,
+    {
+        let mut visited = vec![false; self.internal.len()];
+        let mut regions = Vec::new();
+
+        for (index, point) in self.iter_points().enumerate() {
+            if !visited[index] && is_member(&point) {
+                regions.push(self.fill_from(&point, &can_fill, &mut visited));
+            }
+        }
+
+        regions
+    }
+
     /// Get positions of all values from the grid
-    pub fn get_value(&self, value: char) -> Vec<Point> {
+    pub fn get_value(&self, value: T) -> Vec<Point> {
         self.get_value_if(value, || true)
     }
 
-    pub fn get_value_if<F>(&self, value: char, func: F) -> Vec<Point>
+    pub fn get_value_if<F>(&self, value: T, func: F) -> Vec<Point>
     where
         F: Copy + Fn() -> bool,
     {
-        self.internal
-            .iter()
-            .enumerate()
-            .flat_map(|(i, row)| {
-                row.iter()
-                    .enumerate()
-                    .filter_map(move |(j, c)| match *c == value && func() {
-                        true => Some(Point {
-                            x: j as isize,
-                            y: i as isize,
-                        }),
-                        false => None,
-                    })
+        self.enumerate()
+            .filter_map(|(point, c)| match *c == value && func() {
+                true => Some(point),
+                false => None,
             })
             .collect()
     }
+}
 
+impl<T> Grid<T>
+where
+    T: Display,
+{
     /// Print grid to the console.
     pub fn print(&self) {
         self.print_with_visited(&[]);
@@ -199,9 +397,9 @@ impl Grid {
     /// Print grid to the console. If point is in visited collection show 'O' for this point.
     pub fn print_with_visited(&self, visited: &[Point]) {
         // Go through all rows
-        for i in 0..self.rows {
+        for (i, row) in self.rows_iter().enumerate() {
             // Format whole line and print it only once to the console to speedup writesS
-            let line = self.internal[i]
+            let line = row
                 .iter()
                 .enumerate()
                 .map(|(j, c)| {
@@ -209,8 +407,8 @@ impl Grid {
                         x: j as isize,
                         y: i as isize,
                     }) {
-                        true => 'O',
-                        false => *c,
+                        true => String::from("O"),
+                        false => c.to_string(),
                     }
                 })
                 .collect::<String>();
@@ -220,17 +418,57 @@ impl Grid {
     }
 }
 
-impl Index<Point> for Grid {
-    type Output = char;
+impl Grid<char> {
+    pub fn new_from_lines(lines: &[&str]) -> Result<Self, PuzzleError> {
+        // Parse lines to Vec<Vec<char>>
+        let grid = lines
+            .iter()
+            .map(|line| line.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        Self::new(grid)
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: From<u8>,
+{
+    /// Create new grid from lines of decimal digits, e.g. `Grid<u8>` or `Grid<u32>` with heights
+    /// or costs. Every character in the lines has to be a digit.
+    pub fn new_from_digit_lines(lines: &[&str]) -> Result<Self, PuzzleError> {
+        let grid = lines
+            .iter()
+            .map(|line| {
+                line.chars()
+                    .map(|c| match c.to_digit(10) {
+                        Some(digit) => Ok(T::from(digit as u8)),
+                        None => Err(PuzzleError::InvalidContentError(format!(
+                            "Invalid digit '{}' in line '{}'",
+                            c, line
+                        ))),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(grid)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
 
     fn index(&self, index: Point) -> &Self::Output {
-        &self.internal[index.y as usize][index.x as usize]
+        self.get(&index)
+            .unwrap_or_else(|| panic!("Point {:?} is not in the grid", index))
     }
 }
 
-impl IndexMut<Point> for Grid {
+impl<T> IndexMut<Point> for Grid<T> {
     fn index_mut(&mut self, index: Point) -> &mut Self::Output {
-        &mut self.internal[index.y as usize][index.x as usize]
+        self.get_mut(&index)
+            .unwrap_or_else(|| panic!("Point {:?} is not in the grid", index))
     }
 }
 
@@ -424,4 +662,257 @@ mod tests {
         assert_eq!(grid[Point { x: 1, y: 1 }], '.');
         assert_eq!(grid[Point { x: 2, y: 1 }], '.');
     }
+
+    #[test]
+    fn test_new_from_digit_lines() {
+        let lines = ["0123", "4567", "8990"];
+
+        let result = Grid::<u8>::new_from_digit_lines(&lines);
+        assert!(result.is_ok(), "result: {:?}", result);
+
+        let grid = result.unwrap();
+        assert_eq!(grid.rows, 3);
+        assert_eq!(grid.cols, 4);
+        assert_eq!(grid[Point { x: 0, y: 0 }], 0);
+        assert_eq!(grid[Point { x: 3, y: 0 }], 3);
+        assert_eq!(grid[Point { x: 1, y: 1 }], 5);
+        assert_eq!(grid[Point { x: 2, y: 2 }], 9);
+
+        let result = Grid::<u32>::new_from_digit_lines(&lines);
+        assert!(result.is_ok(), "result: {:?}", result);
+        assert_eq!(result.unwrap()[Point { x: 1, y: 2 }], 9_u32);
+    }
+
+    #[test]
+    fn test_new_from_digit_lines_invalid() {
+        let lines = ["0123", "45x7"];
+
+        let result = Grid::<u32>::new_from_digit_lines(&lines);
+        assert!(
+            matches!(result, Err(PuzzleError::InvalidContentError(_))),
+            "result: {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = Grid::new_from_lines(&["#.#", "..#"]).unwrap();
+
+        let walls = grid.map(|c| *c == '#');
+        assert_eq!(walls.rows(), 2);
+        assert_eq!(walls.cols(), 3);
+        assert!(walls[Point::new(0, 0)]);
+        assert!(!walls[Point::new(1, 0)]);
+        assert!(walls[Point::new(2, 1)]);
+        assert_eq!(
+            walls.get_if(|wall| wall),
+            vec![Point::new(0, 0), Point::new(2, 0), Point::new(2, 1)]
+        );
+    }
+
+    #[test]
+    fn test_map_with_point() {
+        let grid = build_grid();
+
+        let mapped = grid.map_with_point(|point, c| format!("{}{}{}", c, point.x, point.y));
+        assert_eq!(mapped[Point::new(0, 0)], "a00");
+        assert_eq!(mapped[Point::new(2, 0)], "c20");
+        assert_eq!(mapped[Point::new(1, 1)], "e11");
+    }
+
+    #[test]
+    fn test_generic_neighbors_if() {
+        let grid = Grid::<u32>::new_from_digit_lines(&["123", "456", "789"]).unwrap();
+        let center = Point::new(1, 1);
+
+        let result = grid.neighbors_if(&center, &Direction::CARDINAL, |point, _| {
+            grid[*point] > grid[center]
+        });
+        assert_eq!(
+            result,
+            vec![
+                (Point::new(2, 1), Direction::East),
+                (Point::new(1, 2), Direction::South)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_new_ragged() {
+        let result = Grid::new(vec![vec!['a', 'b', 'c'], vec!['d', 'e']]);
+
+        assert!(
+            matches!(result, Err(PuzzleError::GenericError(_))),
+            "result: {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = build_grid();
+
+        assert_eq!(grid.get(&Point::new(0, 0)), Some(&'a'));
+        assert_eq!(grid.get(&Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(&Point::new(3, 0)), None);
+        assert_eq!(grid.get(&Point::new(0, 2)), None);
+        assert_eq!(grid.get(&Point::new(-1, 0)), None);
+
+        if let Some(value) = grid.get_mut(&Point::new(1, 1)) {
+            *value = 'x';
+        }
+        assert_eq!(grid[Point::new(1, 1)], 'x');
+        assert_eq!(grid.get_mut(&Point::new(3, 1)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_range() {
+        let grid = build_grid();
+
+        // Would be a valid index into the flat buffer, but it is not in the grid
+        let _ = grid[Point::new(3, 0)];
+    }
+
+    #[test]
+    fn test_row_and_col() {
+        let grid = build_grid();
+
+        assert_eq!(grid.row(0), &['a', 'b', 'c']);
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.col(0).copied().collect::<Vec<_>>(), vec!['a', 'd']);
+        assert_eq!(grid.col(2).copied().collect::<Vec<_>>(), vec!['c', 'f']);
+        assert_eq!(
+            grid.col(1).rev().copied().collect::<Vec<_>>(),
+            vec!['e', 'b']
+        );
+    }
+
+    #[test]
+    fn test_rows_and_cols_iter() {
+        let grid = build_grid();
+
+        let rows = grid
+            .rows_iter()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows, vec!["abc", "def"]);
+
+        let cols = grid
+            .cols_iter()
+            .map(|col| col.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(cols, vec!["ad", "be", "cf"]);
+
+        assert_eq!(Grid::<char>::default().rows_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_iter_points() {
+        let grid = build_grid();
+
+        assert_eq!(
+            grid.iter_points().collect::<Vec<_>>(),
+            vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(0, 1),
+                Point::new(1, 1),
+                Point::new(2, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enumerate() {
+        let grid = build_grid();
+
+        let cells = grid.enumerate().collect::<Vec<_>>();
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[0], (Point::new(0, 0), &'a'));
+        assert_eq!(cells[2], (Point::new(2, 0), &'c'));
+        assert_eq!(cells[4], (Point::new(1, 1), &'e'));
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = Grid::new_from_lines(&["..#.", ".##.", "#..."]).unwrap();
+
+        let region = grid.flood_fill(&Point::new(0, 0), |_, to| grid[*to] == '.');
+        assert_eq!(
+            region.points(),
+            &[Point::new(0, 0), Point::new(1, 0), Point::new(0, 1)]
+        );
+
+        let region = grid.flood_fill(&Point::new(3, 0), |_, to| grid[*to] == '.');
+        assert_eq!(region.area(), 5);
+
+        let region = grid.flood_fill(&Point::new(4, 0), |_, _| true);
+        assert_eq!(region.area(), 0);
+    }
+
+    #[test]
+    fn test_regions() {
+        let grid = Grid::new_from_lines(&["AAAA", "BBCD", "BBCC", "EEEC"]).unwrap();
+
+        let regions = grid.regions();
+        let metrics = regions
+            .iter()
+            .map(|region| {
+                (
+                    grid[region.points()[0]],
+                    region.area(),
+                    region.perimeter(),
+                    region.sides(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            metrics,
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_regions_with_holes() {
+        let grid = Grid::new_from_lines(&["OOOOO", "OXOXO", "OOOOO", "OXOXO", "OOOOO"]).unwrap();
+
+        let regions = grid.regions();
+        assert_eq!(regions.len(), 5);
+        assert_eq!(regions[0].area(), 21);
+        assert_eq!(regions[0].perimeter(), 36);
+        assert_eq!(regions[0].sides(), 20);
+    }
+
+    #[test]
+    fn test_regions_if() {
+        let grid = Grid::new_from_lines(&["#..#", "#..#", "..##"]).unwrap();
+
+        let regions = grid.regions_if(|c| c == '#');
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[0].points(), &[Point::new(0, 0), Point::new(0, 1)]);
+        assert_eq!(regions[1].area(), 4);
+        assert_eq!(
+            regions[1].bounding_box(),
+            Some((Point::new(2, 0), Point::new(3, 2)))
+        );
+    }
+
+    #[test]
+    fn test_label_regions() {
+        let grid = Grid::new_from_lines(&["aab", "cab"]).unwrap();
+
+        let labels = grid.label_regions();
+        assert_eq!(labels.row(0), &[0, 0, 1]);
+        assert_eq!(labels.row(1), &[2, 0, 1]);
+    }
 }
@@ -1,3 +1,5 @@
 pub mod direction;
 pub mod grid;
+pub mod ocr;
 pub mod point;
+pub mod region;
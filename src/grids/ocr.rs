@@ -0,0 +1,334 @@
+use std::collections::HashSet;
+
+use crate::puzzles::puzzle_error::PuzzleError;
+
+use super::{grid::Grid, point::Point};
+
+/// Glyph definition, every row of the glyph is trimmed to the pixels of the letter only
+type Glyph = (char, &'static [&'static str]);
+
+/// Font 4x6 used by most of the puzzles, letters are 4 pixels wide (with few exceptions)
+const FONT_6: &[Glyph] = &[
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Font 6x10 used by the older puzzles
+const FONT_10: &[Glyph] = &[
+    (
+        'A',
+        &[
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        &[
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        &[
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        &[
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        &[
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        &[
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        &[
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        &[
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+/// Optical character recognition of capital letters rendered as pixels, e.g. answers shown on
+/// the screen of some device
+pub struct Ocr;
+
+impl Ocr {
+    /// Decode letters from the grid.
+    ///
+    /// # Arguments
+    ///
+    /// _grid_ - Grid with rendered letters
+    /// _on_ - Character of the pixel which is turned on, all other characters are turned off
+    ///
+    /// # Returns
+    ///
+    /// Decoded letters on success, error if the letters cannot be recognized
+    pub fn decode_grid(grid: &Grid, on: char) -> Result<String, PuzzleError> {
+        Self::decode_points(&grid.get_value(on))
+    }
+
+    /// Decode letters from the set of turned on pixels. Position of the letters does not matter,
+    /// only the relative position of the pixels.
+    ///
+    /// # Arguments
+    ///
+    /// _points_ - Pixels which are turned on
+    ///
+    /// # Returns
+    ///
+    /// Decoded letters on success, error if the letters cannot be recognized
+    pub fn decode_points(points: &[Point]) -> Result<String, PuzzleError> {
+        let pixels = points.iter().copied().collect::<HashSet<_>>();
+
+        let (min_x, max_x, min_y, max_y) = match (
+            pixels.iter().map(|point| point.x).min(),
+            pixels.iter().map(|point| point.x).max(),
+            pixels.iter().map(|point| point.y).min(),
+            pixels.iter().map(|point| point.y).max(),
+        ) {
+            (Some(min_x), Some(max_x), Some(min_y), Some(max_y)) => (min_x, max_x, min_y, max_y),
+            _ => {
+                return Err(PuzzleError::InvalidContentError(
+                    "No pixels to recognize letters from".to_string(),
+                ));
+            }
+        };
+
+        let height = (max_y - min_y + 1) as usize;
+        let font = match height {
+            6 => FONT_6,
+            10 => FONT_10,
+            _ => {
+                return Err(PuzzleError::InvalidContentError(format!(
+                    "Unsupported letter height {}, expected 6 or 10",
+                    height
+                )));
+            }
+        };
+
+        // Try wider glyphs first so that narrow glyph never matches part of the wider one
+        let mut font = font.to_vec();
+        font.sort_by_key(|(_, rows)| std::cmp::Reverse(rows[0].len()));
+
+        let is_on = |x: isize, y: isize| pixels.contains(&Point::new(x, min_y + y));
+        let is_column_empty = |x: isize| (0..height as isize).all(|y| !is_on(x, y));
+
+        let mut letters = String::new();
+        let mut x = min_x;
+
+        while x <= max_x {
+            // Skip spaces between letters
+            if is_column_empty(x) {
+                x += 1;
+                continue;
+            }
+
+            let (letter, width) = font
+                .iter()
+                .find(|(_, rows)| {
+                    rows.iter().enumerate().all(|(y, row)| {
+                        row.chars()
+                            .enumerate()
+                            .all(|(dx, c)| is_on(x + dx as isize, y as isize) == (c == '#'))
+                    })
+                })
+                .map(|(letter, rows)| (*letter, rows[0].len()))
+                .ok_or_else(|| {
+                    PuzzleError::InvalidContentError(format!(
+                        "Unknown letter at column {}",
+                        x - min_x
+                    ))
+                })?;
+
+            letters.push(letter);
+            x += width as isize;
+        }
+
+        Ok(letters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_grid() {
+        let grid = Grid::new_from_lines(&[
+            "#..#.####.#....#.....##.",
+            "#..#.#....#....#....#..#",
+            "####.###..#....#....#..#",
+            "#..#.#....#....#....#..#",
+            "#..#.#....#....#....#..#",
+            "#..#.####.####.####..##.",
+        ])
+        .unwrap();
+
+        assert_eq!(Ocr::decode_grid(&grid, '#').unwrap(), "HELLO");
+    }
+
+    #[test]
+    fn test_decode_grid_narrow_and_wide_letters() {
+        let grid = Grid::new_from_lines(&[
+            ".###.#...####.",
+            "..#..#...##..#",
+            "..#...#.#.#..#",
+            "..#....#..###.",
+            "..#....#..#...",
+            ".###...#..#...",
+        ])
+        .unwrap();
+
+        // Wide letter 'Y' has no space before the next letter
+        assert_eq!(Ocr::decode_grid(&grid, '#').unwrap(), "IYP");
+    }
+
+    #[test]
+    fn test_decode_points_large_font() {
+        let lines = [
+            "#....#..#....#",
+            "##...#..#....#",
+            "##...#...#..#.",
+            "#.#..#...#..#.",
+            "#.#..#....##..",
+            "#..#.#....##..",
+            "#..#.#...#..#.",
+            "#...##...#..#.",
+            "#...##..#....#",
+            "#....#..#....#",
+        ];
+
+        // Position of the pixels does not matter
+        let points = lines
+            .iter()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|(_, c)| *c == '#')
+                    .map(move |(x, _)| Point::new(x as isize + 100, y as isize - 50))
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(Ocr::decode_points(&points).unwrap(), "NX");
+    }
+
+    #[test]
+    fn test_decode_unknown_letter() {
+        let grid = Grid::new_from_lines(&[
+            "#..#.#...",
+            "#..#.#...",
+            "####.#...",
+            "#..#.#...",
+            "#..#.#...",
+            "#..#.###.",
+        ])
+        .unwrap();
+
+        let result = Ocr::decode_grid(&grid, '#');
+        assert!(
+            matches!(result, Err(PuzzleError::InvalidContentError(_))),
+            "result: {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_height() {
+        let result = Ocr::decode_points(&[Point::new(0, 0), Point::new(0, 3)]);
+        assert!(result.is_err(), "result: {:?}", result);
+
+        let result = Ocr::decode_points(&[]);
+        assert!(result.is_err(), "result: {:?}", result);
+    }
+}
@@ -23,6 +23,11 @@ impl Point {
             Direction::NorthWest => Self::new(self.x - 1, self.y - 1),
         }
     }
+
+    /// Calculate Manhattan distance between two points
+    pub fn manhattan_distance(&self, other: &Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
 }
 
 #[cfg(test)]
@@ -42,4 +47,13 @@ mod tests {
         assert_eq!(point.neighbor(&Direction::West), Point { x: 4, y: 3 });
         assert_eq!(point.neighbor(&Direction::NorthWest), Point { x: 4, y: 2 });
     }
+
+    #[test]
+    fn test_manhattan_distance() {
+        let point = Point { x: 5, y: 3 };
+
+        assert_eq!(point.manhattan_distance(&point), 0);
+        assert_eq!(point.manhattan_distance(&Point { x: 2, y: 7 }), 7);
+        assert_eq!(point.manhattan_distance(&Point { x: -1, y: -1 }), 10);
+    }
 }
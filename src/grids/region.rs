@@ -0,0 +1,166 @@
+use std::collections::HashSet;
+
+use super::{direction::Direction, point::Point};
+
+/// Pairs of neighboring directions together with the diagonal between them, used to detect
+/// corners of the region
+const CORNERS: [(Direction, Direction, Direction); 4] = [
+    (Direction::North, Direction::East, Direction::NorthEast),
+    (Direction::East, Direction::South, Direction::SouthEast),
+    (Direction::South, Direction::West, Direction::SouthWest),
+    (Direction::West, Direction::North, Direction::NorthWest),
+];
+
+/// Set of points connected in [Direction::CARDINAL] directions, e.g. a garden plot or a basin
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Region {
+    points: Vec<Point>,
+    lookup: HashSet<Point>,
+}
+
+impl Region {
+    /// Create new region from the points, points are sorted row by row
+    pub fn new<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        let lookup = points.into_iter().collect::<HashSet<_>>();
+
+        let mut points = lookup.iter().copied().collect::<Vec<_>>();
+        points.sort_by_key(|point| (point.y, point.x));
+
+        Self { points, lookup }
+    }
+
+    /// Get all points of the region sorted row by row
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.lookup.contains(point)
+    }
+
+    /// Number of points in the region
+    pub fn area(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Number of edges between the region and the rest of the world
+    pub fn perimeter(&self) -> usize {
+        self.points
+            .iter()
+            .map(|point| {
+                Direction::CARDINAL
+                    .iter()
+                    .filter(|direction| !self.contains(&point.neighbor(direction)))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of corners, both convex and concave (including corners of the holes)
+    pub fn corners(&self) -> usize {
+        self.points
+            .iter()
+            .map(|point| {
+                CORNERS
+                    .iter()
+                    .filter(|(first, second, diagonal)| {
+                        let first = self.contains(&point.neighbor(first));
+                        let second = self.contains(&point.neighbor(second));
+                        let diagonal = self.contains(&point.neighbor(diagonal));
+
+                        // Convex corner || concave corner
+                        (!first && !second) || (first && second && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of straight sides of the region, equal to the number of corners
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    /// Get bounding box of the region as a top-left and bottom-right point (both included),
+    /// `None` for an empty region
+    pub fn bounding_box(&self) -> Option<(Point, Point)> {
+        let min_x = self.points.iter().map(|point| point.x).min()?;
+        let max_x = self.points.iter().map(|point| point.x).max()?;
+        let min_y = self.points.iter().map(|point| point.y).min()?;
+        let max_y = self.points.iter().map(|point| point.y).max()?;
+
+        Some((Point::new(min_x, min_y), Point::new(max_x, max_y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_region(lines: &[&str]) -> Region {
+        Region::new(lines.iter().enumerate().flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|(_, c)| *c == '#')
+                .map(move |(x, _)| Point::new(x as isize, y as isize))
+        }))
+    }
+
+    #[test]
+    fn test_single_point() {
+        let region = build_region(&["#"]);
+
+        assert_eq!(region.area(), 1);
+        assert_eq!(region.perimeter(), 4);
+        assert_eq!(region.sides(), 4);
+        assert_eq!(
+            region.bounding_box(),
+            Some((Point::new(0, 0), Point::new(0, 0)))
+        );
+    }
+
+    #[test]
+    fn test_l_shape() {
+        let region = build_region(&["#..", "#..", "###"]);
+
+        assert_eq!(region.area(), 5);
+        assert_eq!(region.perimeter(), 12);
+        assert_eq!(region.corners(), 6);
+        assert_eq!(
+            region.bounding_box(),
+            Some((Point::new(0, 0), Point::new(2, 2)))
+        );
+    }
+
+    #[test]
+    fn test_hole() {
+        let region = build_region(&["###", "#.#", "###"]);
+
+        assert_eq!(region.area(), 8);
+        assert_eq!(region.perimeter(), 16);
+        assert_eq!(region.sides(), 8);
+        assert!(!region.contains(&Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_points_sorted() {
+        let region = Region::new([Point::new(1, 1), Point::new(0, 1), Point::new(2, 0)]);
+
+        assert_eq!(
+            region.points(),
+            &[Point::new(2, 0), Point::new(0, 1), Point::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let region = Region::default();
+
+        assert_eq!(region.area(), 0);
+        assert_eq!(region.perimeter(), 0);
+        assert_eq!(region.bounding_box(), None);
+    }
+}
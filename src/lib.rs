@@ -1,3 +1,4 @@
 pub mod env;
 pub mod grids;
 pub mod puzzles;
+pub mod search;
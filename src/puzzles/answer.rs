@@ -0,0 +1,160 @@
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+/// Answer of the puzzle part which keeps its original form. Answers are compared by their
+/// textual representation, i.e. `Answer::from(42_u64) == Answer::from("42")`.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    /// Unsigned number
+    Unsigned(u128),
+
+    /// Signed number
+    Signed(i128),
+
+    /// Text, e.g. letters or a formatted value
+    Text(String),
+}
+
+impl Answer {
+    /// Creates a text answer from any displayable value
+    ///
+    /// # Arguments
+    ///
+    /// _value_ - Value of the answer
+    ///
+    /// # Returns
+    ///
+    /// Text answer with the displayed value
+    pub fn text<T: Display>(value: T) -> Self {
+        Self::Text(value.to_string())
+    }
+
+    /// Get name of the kind of the answer, e.g. `unsigned`
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Signed(_) => "signed",
+            Answer::Text(_) => "text",
+        }
+    }
+
+    /// Check if the answer is a number
+    pub fn is_number(&self) -> bool {
+        !matches!(self, Answer::Text(_))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => self.to_string() == other.to_string(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_string().hash(state);
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Unsigned(value as u128)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Signed(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<&String> for Answer {
+    fn from(value: &String) -> Self {
+        Answer::Text(value.clone())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert!(matches!(Answer::from(42_u32), Answer::Unsigned(42)));
+        assert!(matches!(Answer::from(42_usize), Answer::Unsigned(42)));
+        assert!(matches!(Answer::from(-42_i64), Answer::Signed(-42)));
+        assert!(matches!(Answer::from("ABC"), Answer::Text(ref s) if s == "ABC"));
+        assert!(matches!(Answer::from('x'), Answer::Text(ref s) if s == "x"));
+        assert!(matches!(Answer::text(1.5), Answer::Text(ref s) if s == "1.5"));
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Answer::from(42_u64), Answer::from(42_u8));
+        assert_eq!(Answer::from(42_u64), Answer::from(42_i64));
+        assert_eq!(Answer::from(-1_i32), Answer::from("-1"));
+        assert_ne!(Answer::from(42_u64), Answer::from(43_u64));
+        assert_ne!(Answer::from("042"), Answer::from(42_u64));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(i64::MIN).to_string(), i64::MIN.to_string());
+        assert_eq!(Answer::from("ABC").to_string(), "ABC");
+        assert_eq!(Answer::from(7_u8).kind(), "unsigned");
+        assert!(!Answer::from("7").is_number());
+    }
+}
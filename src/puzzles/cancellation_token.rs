@@ -0,0 +1,125 @@
+use std::{
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+use super::{puzzle::PuzzleResult, puzzle_error::PuzzleError};
+
+thread_local! {
+    /// Cancellation token of the part which is being solved by the current thread
+    static CURRENT: Cell<CancellationToken> = const { Cell::new(CancellationToken { deadline: None }) };
+}
+
+/// Token which lets the solver find out that the time limit of the part being solved is
+/// exceeded. Cancellation is cooperative - long running solver should poll the token and give up
+/// once it is cancelled. The token is never cancelled if the part has no time limit.
+///
+/// ```
+/// use advent_of_code::puzzles::cancellation_token::CancellationToken;
+///
+/// let token = CancellationToken::current();
+///
+/// for _ in 0..1000 {
+///     // Returns timeout error once the time limit is exceeded
+///     token.check()?;
+/// }
+/// # Ok::<(), advent_of_code::puzzles::puzzle_error::PuzzleError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CancellationToken {
+    deadline: Option<(Instant, Duration)>,
+}
+
+impl CancellationToken {
+    /// Create a token which is cancelled once the time limit elapses
+    ///
+    /// # Arguments
+    ///
+    /// _timeout_ - Time limit measured from now
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            deadline: Some((Instant::now() + timeout, timeout)),
+        }
+    }
+
+    /// Get cancellation token of the part being solved by the current thread. Token can be
+    /// copied and passed to other threads.
+    pub fn current() -> Self {
+        CURRENT.with(|current| current.get())
+    }
+
+    /// Check if the time limit of the part is exceeded
+    pub fn is_cancelled(&self) -> bool {
+        self.deadline
+            .is_some_and(|(deadline, _)| Instant::now() >= deadline)
+    }
+
+    /// Check if the time limit of the part is exceeded
+    ///
+    /// # Returns
+    ///
+    /// Empty result if the part can continue, timeout error once the time limit is exceeded
+    pub fn check(&self) -> PuzzleResult {
+        match self.deadline {
+            Some((_, timeout)) if self.is_cancelled() => Err(PuzzleError::TimeoutError(timeout)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Make the token current for the calling thread until the returned guard is dropped
+    pub(crate) fn enter(self) -> CancellationGuard {
+        let previous = CURRENT.with(|current| current.replace(self));
+
+        CancellationGuard { previous }
+    }
+}
+
+/// Restores previous cancellation token of the thread on destruction
+pub(crate) struct CancellationGuard {
+    previous: CancellationToken,
+}
+
+impl Drop for CancellationGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.set(self.previous));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_without_timeout() {
+        let token = CancellationToken::current();
+
+        assert!(!token.is_cancelled());
+        assert!(token.check().is_ok());
+    }
+
+    #[test]
+    fn test_token_with_timeout() {
+        let token = CancellationToken::with_timeout(Duration::ZERO);
+
+        assert!(token.is_cancelled());
+        assert!(
+            matches!(token.check(), Err(PuzzleError::TimeoutError(timeout)) if timeout == Duration::ZERO)
+        );
+
+        let token = CancellationToken::with_timeout(Duration::from_secs(3600));
+
+        assert!(!token.is_cancelled());
+        assert!(token.check().is_ok());
+    }
+
+    #[test]
+    fn test_token_current() {
+        {
+            let _guard = CancellationToken::with_timeout(Duration::ZERO).enter();
+
+            assert!(CancellationToken::current().is_cancelled());
+        }
+
+        assert!(!CancellationToken::current().is_cancelled());
+    }
+}
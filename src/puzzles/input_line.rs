@@ -0,0 +1,289 @@
+use std::{fmt::Display, ops::Deref, ops::Range, str::FromStr};
+
+use super::puzzle_error::{ParseError, PuzzleError};
+
+/// Line of the puzzle input which knows its line number, errors created from the line carry
+/// the position in the input, see [ParseError]. Line dereferences to `str`.
+///
+/// ```
+/// use advent_of_code::puzzles::{input_line::InputLine, puzzle_error::PuzzleError};
+///
+/// let lines = ["1 2", "3 4"];
+/// let mut numbers = Vec::new();
+///
+/// for line in InputLine::iter(&lines) {
+///     for field in line.fields() {
+///         numbers.push(field.parse::<u32>()?);
+///     }
+/// }
+///
+/// assert_eq!(numbers, vec![1, 2, 3, 4]);
+///
+/// // Error points to the second line and the third column
+/// let line = InputLine::new(2, "3 x");
+/// assert!(matches!(line.parse::<u32>(), Err(PuzzleError::ParseError(err)) if err.line == 2));
+/// # Ok::<(), PuzzleError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputLine<'a> {
+    number: usize,
+    content: &'a str,
+}
+
+impl<'a> InputLine<'a> {
+    /// Create a new line
+    ///
+    /// # Arguments
+    ///
+    /// _number_ - Number of the line starting from 1
+    /// _content_ - Content of the line
+    pub fn new(number: usize, content: &'a str) -> Self {
+        Self { number, content }
+    }
+
+    /// Iterate over the lines passed to [PuzzleSolver::parse_input_file](super::puzzle_solver::PuzzleSolver::parse_input_file)
+    ///
+    /// # Arguments
+    ///
+    /// _lines_ - Lines of the input
+    ///
+    /// # Returns
+    ///
+    /// Iterator over the lines numbered from 1
+    pub fn iter(lines: &[&'a str]) -> impl Iterator<Item = InputLine<'a>> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, content)| InputLine::new(i + 1, content))
+    }
+
+    /// Get number of the line starting from 1
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /// Get content of the line
+    pub fn as_str(&self) -> &'a str {
+        self.content
+    }
+
+    /// Parse the whole line without surrounding whitespaces
+    ///
+    /// # Returns
+    ///
+    /// Parsed value or parse error pointing to the line
+    pub fn parse<T>(&self) -> Result<T, PuzzleError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.field(self.content.trim()).parse()
+    }
+
+    /// Get fields of the line separated by whitespaces
+    pub fn fields(&self) -> impl Iterator<Item = InputField<'a>> {
+        let line = *self;
+
+        self.content
+            .split_whitespace()
+            .map(move |value| line.field(value))
+    }
+
+    /// Get fields of the line separated by the separator, surrounding whitespaces of the fields
+    /// are trimmed
+    ///
+    /// # Arguments
+    ///
+    /// _separator_ - Separator of the fields
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = InputField<'a>> {
+        let line = *self;
+
+        self.content
+            .split(separator)
+            .map(move |value| line.field(value.trim()))
+    }
+
+    /// Create parse error pointing to the whole line
+    ///
+    /// # Arguments
+    ///
+    /// _message_ - Description of the error
+    pub fn error(&self, message: impl Into<String>) -> PuzzleError {
+        self.error_at(0..self.content.len(), message)
+    }
+
+    /// Create parse error pointing to the columns of the line
+    ///
+    /// # Arguments
+    ///
+    /// _columns_ - Byte range of the offending part of the line
+    /// _message_ - Description of the error
+    pub fn error_at(&self, columns: Range<usize>, message: impl Into<String>) -> PuzzleError {
+        PuzzleError::ParseError(ParseError {
+            message: message.into(),
+            file: None,
+            line: self.number,
+            columns,
+            content: self.content.to_string(),
+        })
+    }
+
+    fn field(&self, value: &'a str) -> InputField<'a> {
+        // Value is always a slice of the line content
+        let start = value.as_ptr() as usize - self.content.as_ptr() as usize;
+
+        InputField {
+            line: *self,
+            columns: start..start + value.len(),
+        }
+    }
+}
+
+impl Deref for InputLine<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.content
+    }
+}
+
+/// Part of the [InputLine], errors created from the field point to its columns
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputField<'a> {
+    line: InputLine<'a>,
+    columns: Range<usize>,
+}
+
+impl<'a> InputField<'a> {
+    /// Get content of the field
+    pub fn as_str(&self) -> &'a str {
+        &self.line.content[self.columns.clone()]
+    }
+
+    /// Get byte range of the field in the line
+    pub fn columns(&self) -> Range<usize> {
+        self.columns.clone()
+    }
+
+    /// Parse the field
+    ///
+    /// # Returns
+    ///
+    /// Parsed value or parse error pointing to the field
+    pub fn parse<T>(&self) -> Result<T, PuzzleError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.as_str()
+            .parse::<T>()
+            .map_err(|err| self.error(err.to_string()))
+    }
+
+    /// Create parse error pointing to the field
+    ///
+    /// # Arguments
+    ///
+    /// _message_ - Description of the error
+    pub fn error(&self, message: impl Into<String>) -> PuzzleError {
+        self.line.error_at(self.columns.clone(), message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(err: PuzzleError) -> ParseError {
+        match err {
+            PuzzleError::ParseError(err) => err,
+            err => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_iter() {
+        let lines = ["a", "b"];
+        let lines = InputLine::iter(&lines).collect::<Vec<_>>();
+
+        assert_eq!(lines, vec![InputLine::new(1, "a"), InputLine::new(2, "b")]);
+        assert_eq!(lines[1].number(), 2);
+        assert_eq!(lines[1].as_str(), "b");
+        assert_eq!(lines[1].len(), 1);
+    }
+
+    #[test]
+    fn test_parse() {
+        let line = InputLine::new(3, "  42 ");
+        assert_eq!(line.parse::<u32>().unwrap(), 42);
+
+        let line = InputLine::new(3, "  4x ");
+        let err = parse_error(line.parse::<u32>().unwrap_err());
+
+        assert_eq!(err.line, 3);
+        assert_eq!(err.columns, 2..4);
+        assert_eq!(err.content, "  4x ");
+        assert_eq!(err.message, "invalid digit found in string");
+    }
+
+    #[test]
+    fn test_fields() {
+        let line = InputLine::new(1, "12  ab 4");
+        let fields = line.fields().collect::<Vec<_>>();
+
+        assert_eq!(
+            fields
+                .iter()
+                .map(|field| field.as_str())
+                .collect::<Vec<_>>(),
+            vec!["12", "ab", "4"]
+        );
+        assert_eq!(fields[1].columns(), 4..6);
+
+        let err = parse_error(fields[1].parse::<u32>().unwrap_err());
+        assert_eq!(err.columns, 4..6);
+    }
+
+    #[test]
+    fn test_split() {
+        let line = InputLine::new(1, "1, 2,x");
+        let fields = line.split(",").collect::<Vec<_>>();
+
+        assert_eq!(
+            fields
+                .iter()
+                .map(|field| field.as_str())
+                .collect::<Vec<_>>(),
+            vec!["1", "2", "x"]
+        );
+        assert_eq!(fields[1].columns(), 3..4);
+        assert_eq!(fields[2].columns(), 5..6);
+    }
+
+    #[test]
+    fn test_error() {
+        let line = InputLine::new(7, "abc");
+
+        let err = parse_error(line.error("Invalid line"));
+        assert_eq!(err.columns, 0..3);
+        assert_eq!(err.message, "Invalid line");
+
+        let err = parse_error(line.error_at(1..2, "Invalid symbol"));
+        assert_eq!(err.line, 7);
+        assert_eq!(err.columns, 1..2);
+    }
+
+    #[test]
+    fn test_error_invalid_columns() {
+        // Invalid columns do not break rendering of the error
+        let err = InputLine::new(1, "abc").error_at(2..10, "Invalid symbol");
+        assert!(
+            err.to_string().ends_with("1 | abc\n  |   ^"),
+            "err: {}",
+            err
+        );
+
+        let err = InputLine::new(1, "\u{00e9}x").error_at(1..2, "Invalid symbol");
+        assert!(err.to_string().ends_with("  | ^"), "err: {}", err);
+    }
+}
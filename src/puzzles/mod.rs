@@ -1,7 +1,20 @@
+pub mod answer;
+pub mod cancellation_token;
+pub mod input_line;
+pub mod panic_capture;
+pub mod progress_mode;
 pub mod puzzle;
+pub mod puzzle_answers;
+pub mod puzzle_args;
+pub mod puzzle_benchmark;
 pub mod puzzle_error;
+pub mod puzzle_example;
+pub mod puzzle_macros;
+pub mod puzzle_report;
 pub mod puzzle_solver;
 pub mod puzzle_tester;
 pub mod reader;
+pub mod solution_progress;
 pub mod solution_progress_bar;
 pub mod solution_progress_bar_thread;
+pub mod typed_puzzle_solver;
@@ -0,0 +1,135 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use super::puzzle_error::PuzzleError;
+
+/// Installs the panic hook only once per process
+static HOOK: Once = Once::new();
+
+thread_local! {
+    /// Set while the current thread runs code under [catch_panic]
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+
+    /// Location of the last panic captured on the current thread
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Run the closure and turn its panic into [PuzzleError::PanicError] with the panic message and
+/// location. Captured panic is not printed to the standard error, panics outside of the closure
+/// are handled by the previously installed panic hook. State modified by the closure before the
+/// panic is kept as is.
+///
+/// ```
+/// use advent_of_code::puzzles::{panic_capture::catch_panic, puzzle_error::PuzzleError};
+///
+/// let result: Result<(), PuzzleError> = catch_panic(|| panic!("Invalid input"));
+///
+/// assert!(matches!(result, Err(PuzzleError::PanicError(message, _)) if message == "Invalid input"));
+/// ```
+///
+/// # Arguments
+///
+/// _f_ - Closure to run
+///
+/// # Returns
+///
+/// Result of the closure or panic error if the closure panicked
+pub fn catch_panic<R, F>(f: F) -> Result<R, PuzzleError>
+where
+    F: FnOnce() -> Result<R, PuzzleError>,
+{
+    install_hook();
+
+    let capturing = CAPTURING.with(|capturing| capturing.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CAPTURING.with(|current| current.set(capturing));
+
+    match result {
+        Ok(result) => result,
+        Err(payload) => Err(PuzzleError::PanicError(
+            panic_message(payload.as_ref()),
+            LOCATION.with(|location| location.borrow_mut().take()),
+        )),
+    }
+}
+
+fn install_hook() {
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            match CAPTURING.with(|capturing| capturing.get()) {
+                true => LOCATION.with(|location| {
+                    *location.borrow_mut() = info.location().map(|location| location.to_string())
+                }),
+                false => previous(info),
+            }
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("Unknown panic")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch_panic_ok() {
+        let result = catch_panic(|| Ok(5));
+
+        assert!(matches!(result, Ok(5)), "result: {:?}", result);
+
+        let result: Result<(), PuzzleError> =
+            catch_panic(|| Err(PuzzleError::GenericError(String::from("Error"))));
+
+        assert!(
+            matches!(result, Err(PuzzleError::GenericError(_))),
+            "result: {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        let line = line!() + 1;
+        let result: Result<(), PuzzleError> = catch_panic(|| panic!("Wrong answer {}", 42));
+
+        match result {
+            Err(PuzzleError::PanicError(message, location)) => {
+                assert_eq!(message, "Wrong answer 42");
+
+                let location = location.expect("Missing panic location");
+                assert!(
+                    location.contains(&format!("panic_capture.rs:{}", line)),
+                    "location: {}",
+                    location
+                );
+            }
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_catch_panic_unwrap() {
+        let result: Result<u32, PuzzleError> = catch_panic(|| Ok("x".parse::<u32>().unwrap()));
+
+        assert!(
+            matches!(&result, Err(PuzzleError::PanicError(message, Some(_))) if message.contains("InvalidDigit")),
+            "result: {:?}",
+            result
+        );
+    }
+}
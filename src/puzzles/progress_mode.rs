@@ -0,0 +1,108 @@
+use std::io::IsTerminal;
+
+use super::puzzle_error::PuzzleError;
+
+/// Name of environment variable which selects the progress mode, see [ProgressMode::from_env]
+pub const PROGRESS_ENV: &str = "AOC_PROGRESS";
+
+/// Selects whether progress bars are displayed while the puzzle is solved. Without progress
+/// bars no progress thread is spawned and only plain result lines are printed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressMode {
+    /// Display progress bars only if both standard output and standard error are terminals
+    #[default]
+    Auto,
+
+    /// Always display progress bars
+    Always,
+
+    /// Never display progress bars
+    Never,
+}
+
+impl ProgressMode {
+    /// Parse progress mode from its name
+    ///
+    /// # Arguments
+    ///
+    /// _value_ - Name of the mode, one of `auto`, `always` or `never`
+    ///
+    /// # Returns
+    ///
+    /// Progress mode or error for unknown name
+    pub fn parse(value: &str) -> Result<Self, PuzzleError> {
+        match value.trim().to_lowercase().as_str() {
+            "auto" => Ok(ProgressMode::Auto),
+            "always" => Ok(ProgressMode::Always),
+            "never" => Ok(ProgressMode::Never),
+            _ => Err(PuzzleError::InvalidArgumentError(format!(
+                "Invalid progress mode '{}', expected auto, always or never",
+                value
+            ))),
+        }
+    }
+
+    /// Get progress mode from the environment variable _AOC_PROGRESS_
+    ///
+    /// # Returns
+    ///
+    /// Progress mode from the environment variable, [ProgressMode::Auto] if the variable is not
+    /// set or has invalid value
+    pub fn from_env() -> Self {
+        std::env::var(PROGRESS_ENV)
+            .ok()
+            .and_then(|value| Self::parse(&value).ok())
+            .unwrap_or_default()
+    }
+
+    /// Check if progress bars are displayed in this mode
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            ProgressMode::Auto => {
+                std::io::stdout().is_terminal() && std::io::stderr().is_terminal()
+            }
+            ProgressMode::Always => true,
+            ProgressMode::Never => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(ProgressMode::parse("auto").unwrap(), ProgressMode::Auto);
+        assert_eq!(ProgressMode::parse("Always").unwrap(), ProgressMode::Always);
+        assert_eq!(ProgressMode::parse(" never ").unwrap(), ProgressMode::Never);
+
+        let result = ProgressMode::parse("sometimes");
+        assert!(
+            matches!(result, Err(PuzzleError::InvalidArgumentError(_))),
+            "result: {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_from_env() {
+        temp_env::with_var(PROGRESS_ENV, Some("never"), || {
+            assert_eq!(ProgressMode::from_env(), ProgressMode::Never);
+        });
+
+        temp_env::with_var(PROGRESS_ENV, Some("invalid"), || {
+            assert_eq!(ProgressMode::from_env(), ProgressMode::Auto);
+        });
+
+        temp_env::with_var(PROGRESS_ENV, None::<&str>, || {
+            assert_eq!(ProgressMode::from_env(), ProgressMode::Auto);
+        });
+    }
+
+    #[test]
+    fn test_is_enabled() {
+        assert!(ProgressMode::Always.is_enabled());
+        assert!(!ProgressMode::Never.is_enabled());
+    }
+}
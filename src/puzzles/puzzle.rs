@@ -1,32 +1,79 @@
-use std::{path::Path, time::Instant};
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use indicatif::MultiProgress;
 
-use crate::puzzles::solution_progress_bar_thread::SolutionProgressBarThread;
+use crate::{
+    env::project::Project,
+    puzzles::{
+        solution_progress::SolutionProgress,
+        solution_progress_bar_thread::SolutionProgressBarThread,
+    },
+};
 
 use super::{
-    puzzle_error::PuzzleError, puzzle_solver::PuzzleSolver, reader::text_reader::TextReader,
+    answer::Answer,
+    cancellation_token::CancellationToken,
+    panic_capture::catch_panic,
+    progress_mode::ProgressMode,
+    puzzle_answers::PuzzleAnswers,
+    puzzle_args::{PuzzleArgs, PuzzleInput},
+    puzzle_benchmark::{BenchmarkBaseline, BenchmarkStats, PhaseBenchmark, PuzzleBenchmark},
+    puzzle_error::PuzzleError,
+    puzzle_report::{InputReport, PartReport, PuzzleReport, ReportFormat},
+    puzzle_solver::PuzzleSolver,
+    reader::text_reader::TextReader,
 };
 
 /// Definition of the result of the puzzle
 pub type PuzzleResult = Result<(), PuzzleError>;
 
-/// Definition of the result of the solution (part 1, part 2)
-pub type SolutionResult = Result<String, PuzzleError>;
+/// Definition of the result of the solution (part 1, part 2, part 3)
+pub type SolutionResult = Result<Answer, PuzzleError>;
 
 /// Prefixes for console output
 const TIME_PREFIX: &str = "----:---"; // seconds:miliseconds
-const READ_INPUT_FILE_PREFIX: &str = "=> Reader:";
-const PART_1_PREFIX: &str = "=> Part 1:";
-const PART_2_PREFIX: &str = "=> Part 2:";
+const READ_INPUT_FILE_PREFIX: &str = "=> Reader";
+const PART_PREFIX: &str = "=> Part";
 
 #[derive(Default)]
 pub struct Puzzle<T> {
     /// Text file reader
     reader: Option<TextReader>,
 
+    /// Text file readers, one for every part of the puzzle
+    part_readers: Vec<TextReader>,
+
     /// Puzzle solver
     solver: T,
+
+    /// Solve only this part, all parts are solved if not set
+    part: Option<usize>,
+
+    /// Print only the answers
+    quiet: bool,
+
+    /// Print nothing, results are available only in the returned report
+    silent: bool,
+
+    /// Known answers the parts are checked against
+    answers: Option<PuzzleAnswers>,
+
+    /// Selects whether progress bars are displayed, environment variable decides if not set
+    progress: Option<ProgressMode>,
+
+    /// Time limit of every part, parts are not limited if not set
+    timeout: Option<Duration>,
+
+    /// Solves independent parts in parallel, set only for solvers which are `Sync`
+    parallel: Option<PartsSolver<T>>,
 }
 
+/// Solves the given parts of the puzzle and reports them in the same order
+type PartsSolver<T> = fn(&Puzzle<T>, &Instant, &[usize]) -> Vec<PartReport>;
+
 impl<T> Puzzle<T>
 where
     T: PuzzleSolver,
@@ -40,7 +87,15 @@ where
     pub fn new() -> Self {
         Self {
             reader: None,
+            part_readers: Vec::new(),
             solver: T::new(),
+            part: None,
+            quiet: false,
+            silent: false,
+            answers: None,
+            progress: None,
+            timeout: None,
+            parallel: None,
         }
     }
 
@@ -57,8 +112,228 @@ where
     pub fn new_with_reader(path: &Path) -> Self {
         Self {
             reader: Some(TextReader::new(path)),
+            part_readers: Vec::new(),
+            solver: T::new(),
+            part: None,
+            quiet: false,
+            silent: false,
+            answers: None,
+            progress: None,
+            timeout: None,
+            parallel: None,
+        }
+    }
+
+    /// Creates new instance of the Puzzle with a file reader for every part, i.e. every part of
+    /// the puzzle has its own input file (e.g. Everybody Codes quests). Input file of the part
+    /// is parsed by [PuzzleSolver::parse_input_part] right before the part is solved.
+    ///
+    /// # Arguments
+    ///
+    /// _paths_ - Paths to the input files with puzzle content, ordered by parts
+    ///
+    /// # Returns
+    ///
+    /// Instance of Puzzle specialized for concrete PuzzleSolver
+    pub fn new_with_part_readers<P>(paths: &[P]) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        Self {
+            reader: None,
+            part_readers: paths
+                .iter()
+                .map(|path| TextReader::new(path.as_ref()))
+                .collect(),
             solver: T::new(),
+            part: None,
+            quiet: false,
+            silent: false,
+            answers: None,
+            progress: None,
+            timeout: None,
+            parallel: None,
+        }
+    }
+
+    /// Creates new instance of the Puzzle which reads the input from the standard input.
+    ///
+    /// # Returns
+    ///
+    /// Instance of Puzzle specialized for concrete PuzzleSolver
+    pub fn new_with_stdin() -> Self {
+        Self {
+            reader: Some(TextReader::new_stdin()),
+            ..Self::new()
+        }
+    }
+
+    /// Creates new instance of the Puzzle reading input files from the project _resources_
+    /// directory. If _resources/<name>_1.txt_ exists every part reads its own input file
+    /// _resources/<name>_N.txt_, otherwise all parts share _resources/<name>.txt_.
+    /// Known answers are not loaded, use [Puzzle::set_answers] to check the answers.
+    ///
+    /// # Arguments
+    ///
+    /// _name_ - Name of the input file without extension, e.g. `input` or `example`
+    ///
+    /// # Returns
+    ///
+    /// Instance of Puzzle specialized for concrete PuzzleSolver
+    pub fn new_from_resources(name: &str) -> Self {
+        let project = Project::new();
+        let first_part_file = project.resource_file(&format!("{}_1.txt", name));
+
+        match first_part_file.exists() {
+            true => {
+                let parts = T::new().get_parts_count();
+                let paths = (1..=parts)
+                    .map(|part| project.resource_file(&format!("{}_{}.txt", name, part)))
+                    .collect::<Vec<_>>();
+
+                Self::new_with_part_readers(&paths)
+            }
+            false => Self::new_with_reader(&project.resource_file(&format!("{}.txt", name))),
+        }
+    }
+
+    /// Creates the puzzle according to the command line arguments of the process and solves it.
+    /// Intended to be used directly from `main` of the puzzle binary, see [PuzzleArgs::usage]
+    /// for supported options.
+    ///
+    /// # Returns
+    ///
+    /// Successful result or specific error occurred during the solving of the puzzle
+    pub fn run_from_args() -> PuzzleResult {
+        let args = PuzzleArgs::parse(std::env::args().skip(1))?;
+        Self::run_with_args(&args)
+    }
+
+    /// Creates the puzzle according to the parsed command line arguments and solves it.
+    ///
+    /// # Arguments
+    ///
+    /// _args_ - Parsed command line arguments
+    ///
+    /// # Returns
+    ///
+    /// Successful result or specific error occurred during the solving of the puzzle
+    pub fn run_with_args(args: &PuzzleArgs) -> PuzzleResult {
+        Self::run(args, None)
+    }
+
+    fn run(args: &PuzzleArgs, parallel: Option<PartsSolver<T>>) -> PuzzleResult {
+        if args.help {
+            println!("{}", PuzzleArgs::usage());
+            return Ok(());
+        }
+
+        let mut puzzle = match &args.input {
+            PuzzleInput::Default => Self::new_from_resources("input"),
+            PuzzleInput::Example => Self::new_from_resources("example"),
+            PuzzleInput::File(path) => Self::new_with_reader(path),
+            PuzzleInput::Stdin => Self::new_with_stdin(),
+        };
+
+        puzzle.set_part(args.part);
+        puzzle.set_quiet(args.quiet);
+        puzzle.set_progress(args.progress);
+        puzzle.set_timeout(args.timeout);
+        puzzle.parallel = parallel;
+
+        // Known answers belong to the default input only
+        if args.input == PuzzleInput::Default {
+            puzzle.set_answers(PuzzleAnswers::load_from_resources()?);
+        }
+
+        if let Some(runs) = args.bench {
+            return puzzle.run_benchmark(args, runs);
         }
+
+        puzzle.set_silent(args.format != ReportFormat::Text);
+
+        if args.format == ReportFormat::Csv {
+            println!("{}", PuzzleReport::csv_header());
+        }
+
+        for run in 0..args.repeat {
+            // Every run starts with a fresh solver
+            if run > 0 {
+                puzzle.solver = T::new();
+            }
+
+            let report = puzzle.solve()?;
+
+            match args.format {
+                ReportFormat::Text => {}
+                ReportFormat::Json => println!("{}", report.to_json()),
+                ReportFormat::Csv => println!("{}", report.to_csv_rows()),
+            }
+
+            report.into_result()?;
+        }
+
+        Ok(())
+    }
+
+    fn run_benchmark(&mut self, args: &PuzzleArgs, runs: usize) -> PuzzleResult {
+        let mut benchmark = self.benchmark(runs, args.warmup)?;
+
+        if let Some(path) = &args.baseline {
+            benchmark.baseline = Some(BenchmarkBaseline::load(path)?);
+        }
+
+        if !args.quiet {
+            println!("{}", benchmark.description);
+            println!();
+        }
+        print!("{}", benchmark);
+
+        if let Some(path) = &args.save_baseline {
+            BenchmarkBaseline::new(&benchmark).save(path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Solve only the given part of the puzzle, all parts are solved if `None`
+    pub fn set_part(&mut self, part: Option<usize>) {
+        self.part = part;
+    }
+
+    /// Print only the answers, i.e. no description, no progress bars and no timing
+    pub fn set_quiet(&mut self, quiet: bool) {
+        self.quiet = quiet;
+    }
+
+    /// Select whether progress bars are displayed, if `None` the mode is taken from the
+    /// environment variable _AOC_PROGRESS_, see [ProgressMode::from_env]
+    pub fn set_progress(&mut self, progress: Option<ProgressMode>) {
+        self.progress = progress;
+    }
+
+    /// Set time limit of every part. Part which exceeds the limit is reported as failed with
+    /// [PuzzleError::TimeoutError] and the remaining parts are still solved.
+    ///
+    /// Cancellation is cooperative only - the part is not interrupted, the solver must poll
+    /// [CancellationToken::current] to give up once the limit is exceeded. Solver which does not
+    /// poll the token runs until it finishes and only then is reported as timed out, a part
+    /// which never finishes blocks the remaining parts.
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Check answers of the parts against the known answers, see [PuzzleAnswers]. Answers are
+    /// not checked unless they are set, only [Puzzle::run_from_args] loads them from
+    /// _resources/answers.txt_ for the default input.
+    pub fn set_answers(&mut self, answers: Option<PuzzleAnswers>) {
+        self.answers = answers;
+    }
+
+    /// Print nothing to the console, results are available only in the report returned by
+    /// [Puzzle::solve]
+    pub fn set_silent(&mut self, silent: bool) {
+        self.silent = silent;
     }
 
     /// Solve puzzle - there are multiple steps which are done in a sequence:
@@ -66,30 +341,188 @@ where
     /// - Read input file if requested
     /// - Solve puzzle part 1
     /// - Solve puzzle part 2
+    /// - Solve puzzle part 3 if the puzzle has three parts
+    ///
+    /// If every part has its own input file the file is read right before the part is solved.
+    /// If the parts share state (see [PuzzleSolver::parts_share_state]) and only a later part is
+    /// selected the previous parts are solved first without reporting, with their own input files
+    /// if every part has one.
+    /// Panic of the solver is reported as [PuzzleError::PanicError] with the panic location.
+    /// Failure of a part does not stop solving of the remaining parts, failure of an input file
+    /// skips all parts which depend on it. Such failures are recorded in the returned report,
+    /// use [PuzzleReport::into_result] to turn them into an error.
+    /// Checking of the answers is opt-in, answers are checked only if known answers were set by
+    /// [Puzzle::set_answers], otherwise correctness of the parts is not known.
     ///
     /// # Returns
     ///
-    /// Successful result or specific error occurred during the solving of the puzzle
-    pub fn solve(&mut self) -> PuzzleResult {
-        println!("{}", self.solver.get_description());
-        println!();
+    /// Report with answers, timing and errors of the puzzle, or error if the puzzle could not be
+    /// solved at all (e.g. invalid part or wrong number of input files)
+    pub fn solve(&mut self) -> Result<PuzzleReport, PuzzleError> {
+        let mut report = PuzzleReport::new(self.solver.get_description());
+
+        if !self.quiet && !self.silent {
+            println!("{}", self.solver.get_description());
+            println!();
+        }
 
         let timer = Instant::now();
+        let parts = self.selected_parts()?;
 
-        // Read input file if present
-        self.read_input_file(&timer)?;
+        if self.part_readers.is_empty() {
+            // Read input file if present, parts cannot be solved without the input
+            let input = self.read_input_file(&timer);
+            let failed = input.error.is_some();
+            report.inputs.push(input);
 
-        // Solve puzzle part 1
-        self.solve_part_1(&timer)?;
+            // Solve all parts of the puzzle
+            if !failed {
+                match self.solve_previous_parts(parts[0]) {
+                    Ok(()) => match self.parallel {
+                        Some(solve_parts) if self.can_solve_in_parallel(&parts) => {
+                            report.parts.extend(solve_parts(self, &timer, &parts));
+                        }
+                        _ => {
+                            for part in parts {
+                                report.parts.push(self.solve_part(&timer, part));
+                            }
+                        }
+                    },
+                    Err(err) => report
+                        .parts
+                        .push(self.previous_parts_failed(&timer, parts[0], err)),
+                }
+            }
+        } else {
+            if self.part_readers.len() != self.solver.get_parts_count() {
+                return Err(PuzzleError::GenericError(format!(
+                    "Puzzle has {} parts but {} input files were provided",
+                    self.solver.get_parts_count(),
+                    self.part_readers.len()
+                )));
+            }
 
-        // Solve puzzle part 2
-        self.solve_part_2(&timer)?;
+            match self.solve_previous_part_inputs(parts[0]) {
+                Ok(()) => {
+                    // Read input file of the part and solve it
+                    for part in parts {
+                        let input = self.read_part_input_file(&timer, part);
+                        let failed = input.error.is_some();
+                        report.inputs.push(input);
 
-        // If we get here everything is fine
-        Ok(())
+                        if !failed {
+                            report.parts.push(self.solve_part(&timer, part));
+                        }
+                    }
+                }
+                Err(err) => report
+                    .parts
+                    .push(self.previous_parts_failed(&timer, parts[0], err)),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Benchmark the puzzle - the puzzle is solved repeatedly with a fresh solver and nothing
+    /// printed to the console, parsing of every input file and solving of every part is measured
+    /// separately. Warm-up runs are solved the same way but are not measured.
+    ///
+    /// # Arguments
+    ///
+    /// _runs_ - Number of measured runs
+    /// _warmup_ - Number of warm-up runs solved before the measured runs
+    ///
+    /// # Returns
+    ///
+    /// Statistics of every phase of the puzzle or the first error occurred during solving
+    pub fn benchmark(
+        &mut self,
+        runs: usize,
+        warmup: usize,
+    ) -> Result<PuzzleBenchmark, PuzzleError> {
+        let silent = self.silent;
+        self.silent = true;
+
+        let result = self.benchmark_runs(runs, warmup);
+
+        self.silent = silent;
+        result
+    }
+
+    fn benchmark_runs(
+        &mut self,
+        runs: usize,
+        warmup: usize,
+    ) -> Result<PuzzleBenchmark, PuzzleError> {
+        let mut samples: Vec<(String, Vec<Duration>)> = Vec::new();
+
+        for run in 0..warmup + runs {
+            // Every run starts with a fresh solver
+            self.solver = T::new();
+
+            let report = self.solve()?;
+            let phases = report
+                .inputs
+                .iter()
+                .map(|input| match input.part {
+                    Some(part) => (format!("parse {}", part), input.duration),
+                    None => (String::from("parse"), input.duration),
+                })
+                .chain(
+                    report
+                        .parts
+                        .iter()
+                        .map(|part| (format!("part {}", part.part), part.duration)),
+                )
+                .collect::<Vec<_>>();
+
+            report.into_result()?;
+
+            if run < warmup {
+                continue;
+            }
+
+            for (name, duration) in phases {
+                match samples.iter_mut().find(|(phase, _)| *phase == name) {
+                    Some((_, durations)) => durations.push(duration),
+                    None => samples.push((name, vec![duration])),
+                }
+            }
+        }
+
+        let phases = samples
+            .into_iter()
+            .filter_map(|(name, durations)| {
+                BenchmarkStats::from_samples(&durations).map(|stats| PhaseBenchmark { name, stats })
+            })
+            .collect();
+
+        Ok(PuzzleBenchmark {
+            description: self.solver.get_description().to_string(),
+            phases,
+            baseline: None,
+        })
     }
 
-    fn print_result(timer: &Instant, prefix: &str, result: &str) {
+    fn selected_parts(&self) -> Result<Vec<usize>, PuzzleError> {
+        let parts = self.solver.get_parts_count();
+
+        match self.part {
+            Some(part) if (1..=parts).contains(&part) => Ok(vec![part]),
+            Some(part) => Err(PuzzleError::InvalidArgumentError(format!(
+                "Puzzle does not have part {}, valid parts are 1..={}",
+                part, parts
+            ))),
+            None => Ok((1..=parts).collect()),
+        }
+    }
+
+    fn print_result(&self, timer: &Instant, prefix: &str, result: &str) {
+        if self.quiet || self.silent {
+            return;
+        }
+
         println!(
             "{:04}:{:03} {} {}",
             timer.elapsed().as_secs(),
@@ -99,60 +532,352 @@ where
         );
     }
 
-    fn read_input_file(&mut self, timer: &Instant) -> PuzzleResult {
+    fn start_progress_in(
+        &self,
+        multi_progress: &MultiProgress,
+        prefix: &str,
+    ) -> Option<SolutionProgressBarThread> {
+        if !self.is_progress_enabled() {
+            return None;
+        }
+
+        let mut progress = SolutionProgressBarThread::new_in(
+            multi_progress,
+            &format!("{} {}", TIME_PREFIX, prefix),
+        );
+        progress.run();
+
+        Some(progress)
+    }
+
+    fn is_progress_enabled(&self) -> bool {
+        if self.quiet || self.silent {
+            return false;
+        }
+
+        self.progress
+            .unwrap_or_else(ProgressMode::from_env)
+            .is_enabled()
+    }
+
+    fn start_progress(&self, prefix: &str) -> Option<SolutionProgressBarThread> {
+        if !self.is_progress_enabled() {
+            return None;
+        }
+
+        let mut progress = SolutionProgressBarThread::new(&format!("{} {}", TIME_PREFIX, prefix));
+        progress.run();
+
+        Some(progress)
+    }
+
+    fn read_input_file(&mut self, timer: &Instant) -> InputReport {
+        let prefix = format!("{}:", READ_INPUT_FILE_PREFIX);
+        let start = Instant::now();
         let result;
 
         {
-            let prefix = format!("{} {}", TIME_PREFIX, READ_INPUT_FILE_PREFIX);
-            let mut progress = SolutionProgressBarThread::new(&prefix);
-            progress.run();
+            let _progress = self.start_progress(&prefix);
 
             result = match &self.reader {
-                Some(reader) => {
-                    // Read lines from input file
-                    let lines = reader.read_lines()?;
+                Some(reader) => reader.read_lines().and_then(|lines| {
                     let lines = lines.iter().map(|line| line.as_str()).collect::<Vec<_>>();
 
                     // Parse input file and report possible error
-                    self.solver.parse_input_file(&lines)?;
-
-                    format!("Done [{}]", reader.get_file_path().to_string_lossy())
-                }
-                None => String::from("No input file"),
+                    catch_panic(|| self.solver.parse_input_file(&lines))
+                        .map_err(|err| err.with_file(&reader.get_file_path_as_string()))
+                }),
+                None => Ok(()),
             };
         }
 
-        Self::print_result(timer, READ_INPUT_FILE_PREFIX, &result);
-        Ok(())
+        let input = self
+            .reader
+            .as_ref()
+            .map(|reader| reader.get_file_path().to_string_lossy().to_string());
+
+        let report = InputReport {
+            part: None,
+            input,
+            duration: start.elapsed(),
+            error: result.err(),
+        };
+
+        let message = match (&report.input, &report.error) {
+            (_, Some(err)) => format!("Failed [{}]", err),
+            (Some(input), None) => format!("Done [{}]", input),
+            (None, None) => String::from("No input file"),
+        };
+
+        self.print_result(timer, &prefix, &message);
+        report
     }
 
-    fn solve_part_1(&self, timer: &Instant) -> PuzzleResult {
+    fn read_part_input_file(&mut self, timer: &Instant, part: usize) -> InputReport {
+        let prefix = format!("{} {}:", READ_INPUT_FILE_PREFIX, part);
+        let start = Instant::now();
+        let reader = &self.part_readers[part - 1];
         let result;
 
         {
-            let prefix = format!("{} {}", TIME_PREFIX, PART_1_PREFIX);
-            let mut progress = SolutionProgressBarThread::new(&prefix);
-            progress.run();
+            let _progress = self.start_progress(&prefix);
+
+            result = reader.read_lines().and_then(|lines| {
+                let lines = lines.iter().map(|line| line.as_str()).collect::<Vec<_>>();
+
+                // Parse input file of the part and report possible error
+                catch_panic(|| self.solver.parse_input_part(part, &lines))
+                    .map_err(|err| err.with_file(&reader.get_file_path_as_string()))
+            });
+        }
+
+        let report = InputReport {
+            part: Some(part),
+            input: Some(reader.get_file_path().to_string_lossy().to_string()),
+            duration: start.elapsed(),
+            error: result.err(),
+        };
+
+        let message = match &report.error {
+            Some(err) => format!("Failed [{}]", err),
+            None => format!("Done [{}]", report.input.as_deref().unwrap_or_default()),
+        };
+
+        self.print_result(timer, &prefix, &message);
+        report
+    }
+
+    fn can_solve_in_parallel(&self, parts: &[usize]) -> bool {
+        parts.len() > 1 && !self.solver.parts_share_state()
+    }
+
+    fn solve_previous_parts(&mut self, part: usize) -> PuzzleResult {
+        // Parts sharing state continue from the state left by the previous parts
+        if self.solver.parts_share_state() {
+            for previous in 1..part {
+                catch_panic(|| self.solver.solve_part_mut(previous))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn solve_previous_part_inputs(&mut self, part: usize) -> PuzzleResult {
+        // Parts sharing state continue from the state left by the previous parts, every previous
+        // part is solved with its own input file
+        if self.solver.parts_share_state() {
+            for previous in 1..part {
+                let reader = &self.part_readers[previous - 1];
+                let lines = reader.read_lines()?;
+                let lines = lines.iter().map(|line| line.as_str()).collect::<Vec<_>>();
 
-            result = self.solver.part_1()?;
+                catch_panic(|| self.solver.parse_input_part(previous, &lines))
+                    .map_err(|err| err.with_file(&reader.get_file_path_as_string()))?;
+                catch_panic(|| self.solver.solve_part_mut(previous))?;
+            }
         }
 
-        Self::print_result(timer, PART_1_PREFIX, &result);
         Ok(())
     }
 
-    fn solve_part_2(&self, timer: &Instant) -> PuzzleResult {
+    fn previous_parts_failed(&self, timer: &Instant, part: usize, err: PuzzleError) -> PartReport {
+        let prefix = format!("{} {}:", PART_PREFIX, part);
+        let err = PuzzleError::GenericError(format!("Previous part failed [{}]", err));
+
+        self.print_result(timer, &prefix, &format!("Failed [{}]", err));
+
+        PartReport {
+            part,
+            answer: None,
+            expected: None,
+            duration: Duration::ZERO,
+            error: Some(err),
+        }
+    }
+
+    fn solve_part(&mut self, timer: &Instant, part: usize) -> PartReport {
+        let prefix = format!("{} {}:", PART_PREFIX, part);
+        let start = Instant::now();
         let result;
 
         {
-            let prefix = format!("{} {}", TIME_PREFIX, PART_2_PREFIX);
-            let mut progress = SolutionProgressBarThread::new(&prefix);
-            progress.run();
+            let progress = self.start_progress(&prefix);
+            let _current = progress
+                .as_ref()
+                .map(|progress| SolutionProgress::enter(progress.progress()));
+            let _cancellation = self
+                .timeout
+                .map(|timeout| CancellationToken::with_timeout(timeout).enter());
 
-            result = self.solver.part_2()?;
+            // Only parts sharing state are solved with mutable access to the solver
+            result = catch_panic(|| match self.solver.parts_share_state() {
+                true => self.solver.solve_part_mut(part),
+                false => self.solver.solve_part(part),
+            });
         }
 
-        Self::print_result(timer, PART_2_PREFIX, &result);
-        Ok(())
+        self.part_report(timer, part, result, start.elapsed())
+    }
+
+    fn part_report(
+        &self,
+        timer: &Instant,
+        part: usize,
+        result: SolutionResult,
+        duration: Duration,
+    ) -> PartReport {
+        let prefix = format!("{} {}:", PART_PREFIX, part);
+        let expected = self
+            .answers
+            .as_ref()
+            .and_then(|answers| answers.get(part))
+            .map(|answer| answer.to_string());
+
+        // Part which exceeds the time limit fails even if the solver did not give up
+        let result = match self.timeout {
+            Some(timeout) if duration > timeout => Err(PuzzleError::TimeoutError(timeout)),
+            _ => result,
+        };
+
+        match result {
+            Ok(answer) => {
+                let report = PartReport {
+                    part,
+                    answer: Some(answer),
+                    expected,
+                    duration,
+                    error: None,
+                };
+                let answer = report
+                    .answer
+                    .as_ref()
+                    .map(|answer| answer.to_string())
+                    .unwrap_or_default();
+
+                // Answers are printed even in quiet mode
+                match (self.quiet, self.silent) {
+                    (_, true) => {}
+                    (true, false) => println!("{}", answer),
+                    (false, false) => {
+                        let result = match (report.is_correct(), &report.expected) {
+                            (Some(true), _) => format!("{} \u{2713}", answer),
+                            (Some(false), Some(expected)) => {
+                                format!("{} \u{2717} [expected {}]", answer, expected)
+                            }
+                            _ => answer,
+                        };
+
+                        self.print_result(timer, &prefix, &result)
+                    }
+                }
+
+                report
+            }
+            Err(err) => {
+                self.print_result(timer, &prefix, &format!("Failed [{}]", err));
+
+                PartReport {
+                    part,
+                    answer: None,
+                    expected,
+                    duration,
+                    error: Some(err),
+                }
+            }
+        }
+    }
+}
+
+impl<T> Puzzle<T>
+where
+    T: PuzzleSolver + Sync,
+{
+    /// Same as [Puzzle::run_from_args] but independent parts are solved in parallel, see
+    /// [Puzzle::set_parallel]
+    ///
+    /// # Returns
+    ///
+    /// Successful result or specific error occurred during the solving of the puzzle
+    pub fn run_parallel_from_args() -> PuzzleResult {
+        let args = PuzzleArgs::parse(std::env::args().skip(1))?;
+        Self::run_parallel_with_args(&args)
+    }
+
+    /// Same as [Puzzle::run_with_args] but independent parts are solved in parallel, see
+    /// [Puzzle::set_parallel]
+    ///
+    /// # Arguments
+    ///
+    /// _args_ - Parsed command line arguments
+    ///
+    /// # Returns
+    ///
+    /// Successful result or specific error occurred during the solving of the puzzle
+    pub fn run_parallel_with_args(args: &PuzzleArgs) -> PuzzleResult {
+        Self::run(args, Some(Self::solve_parts_in_parallel))
+    }
+
+    /// Solve parts in parallel on separate threads after the input file is parsed. Applies
+    /// only to puzzles whose parts share the input file and do not share state, see
+    /// [PuzzleSolver::parts_share_state].
+    pub fn set_parallel(&mut self, parallel: bool) {
+        self.parallel = match parallel {
+            true => Some(Self::solve_parts_in_parallel),
+            false => None,
+        };
+    }
+
+    fn solve_parts_in_parallel(&self, timer: &Instant, parts: &[usize]) -> Vec<PartReport> {
+        let multi_progress = MultiProgress::new();
+
+        let results = std::thread::scope(|scope| {
+            let handles = parts
+                .iter()
+                .map(|part| {
+                    let part = *part;
+                    let progress = self
+                        .start_progress_in(&multi_progress, &format!("{} {}:", PART_PREFIX, part));
+
+                    scope.spawn(move || {
+                        let current = progress
+                            .as_ref()
+                            .map(|progress| SolutionProgress::enter(progress.progress()));
+                        let cancellation = self
+                            .timeout
+                            .map(|timeout| CancellationToken::with_timeout(timeout).enter());
+
+                        let start = Instant::now();
+                        let result = catch_panic(|| self.solver.solve_part(part));
+                        let duration = start.elapsed();
+
+                        // Stop the spinner as soon as the part is solved
+                        drop(cancellation);
+                        drop(current);
+                        drop(progress);
+                        (result, duration)
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .zip(parts)
+                .map(|(handle, part)| {
+                    handle.join().unwrap_or_else(|_| {
+                        (
+                            Err(PuzzleError::GenericError(format!("Part {} panicked", part))),
+                            Duration::ZERO,
+                        )
+                    })
+                })
+                .collect::<Vec<_>>()
+        });
+
+        // Results are printed once all spinners are finished
+        results
+            .into_iter()
+            .zip(parts)
+            .map(|((result, duration), part)| self.part_report(timer, *part, result, duration))
+            .collect()
     }
 }
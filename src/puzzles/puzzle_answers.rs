@@ -0,0 +1,163 @@
+use std::{collections::HashMap, path::Path};
+
+use crate::env::project::Project;
+
+use super::{puzzle_error::PuzzleError, reader::text_reader::TextReader};
+
+/// Name of the answers file in the project _resources_ directory
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Known answers of the puzzle parts. Answers are stored in a text file with a line
+/// `part_<N> = <answer>` for every known part, empty lines and lines starting with `#` are
+/// ignored:
+///
+/// ```text
+/// # Day 1: Trebuchet?!
+/// part_1 = 54390
+/// part_2 = 54277
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuzzleAnswers {
+    answers: HashMap<usize, String>,
+}
+
+impl PuzzleAnswers {
+    /// Creates new instance without any answer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse answers from lines of the answers file
+    ///
+    /// # Arguments
+    ///
+    /// _lines_ - Lines of the answers file
+    ///
+    /// # Returns
+    ///
+    /// Parsed answers or error if a line has invalid format
+    pub fn parse(lines: &[&str]) -> Result<Self, PuzzleError> {
+        let mut answers = Self::new();
+
+        for line in lines {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (part, answer) = line
+                .split_once('=')
+                .and_then(|(key, answer)| {
+                    key.trim()
+                        .strip_prefix("part_")
+                        .and_then(|part| part.parse::<usize>().ok())
+                        .filter(|part| *part > 0)
+                        .map(|part| (part, answer.trim()))
+                })
+                .ok_or_else(|| {
+                    PuzzleError::InvalidContentError(format!(
+                        "Invalid answer line '{}', expected 'part_<N> = <answer>'",
+                        line
+                    ))
+                })?;
+
+            answers.set(part, answer);
+        }
+
+        Ok(answers)
+    }
+
+    /// Loads answers from the file
+    ///
+    /// # Arguments
+    ///
+    /// _path_ - Path to the answers file
+    ///
+    /// # Returns
+    ///
+    /// Loaded answers or error if the file cannot be read or has invalid content
+    pub fn load(path: &Path) -> Result<Self, PuzzleError> {
+        let lines = TextReader::new(path).read_lines()?;
+        let lines = lines.iter().map(|line| line.as_str()).collect::<Vec<_>>();
+
+        Self::parse(&lines)
+    }
+
+    /// Loads answers from _resources/answers.txt_ of the project
+    ///
+    /// # Returns
+    ///
+    /// Loaded answers, `None` if the project has no answers file, or error if the file cannot
+    /// be read or has invalid content
+    pub fn load_from_resources() -> Result<Option<Self>, PuzzleError> {
+        let path = Project::new().resource_file(ANSWERS_FILE);
+
+        match path.exists() {
+            true => Self::load(&path).map(Some),
+            false => Ok(None),
+        }
+    }
+
+    /// Get known answer of the part, `None` if the answer is not known
+    pub fn get(&self, part: usize) -> Option<&str> {
+        self.answers.get(&part).map(|answer| answer.as_str())
+    }
+
+    /// Set known answer of the part
+    pub fn set(&mut self, part: usize, answer: &str) {
+        self.answers.insert(part, answer.to_string());
+    }
+
+    /// Check the answer of the part
+    ///
+    /// # Returns
+    ///
+    /// `Some(true)` if the answer matches the known answer, `Some(false)` if it does not match
+    /// and `None` if the answer of the part is not known
+    pub fn check(&self, part: usize, answer: &str) -> Option<bool> {
+        self.get(part).map(|expected| expected == answer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let result =
+            PuzzleAnswers::parse(&["# Day 1: Test", "", "part_1 = 42", "  part_2=ABC DEF  "]);
+
+        assert!(result.is_ok(), "result: {:?}", result);
+
+        let answers = result.unwrap();
+        assert_eq!(answers.get(1), Some("42"));
+        assert_eq!(answers.get(2), Some("ABC DEF"));
+        assert_eq!(answers.get(3), None);
+
+        assert_eq!(answers.check(1, "42"), Some(true));
+        assert_eq!(answers.check(2, "ABC"), Some(false));
+        assert_eq!(answers.check(3, "1"), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for line in [
+            "42",
+            "part = 42",
+            "part_x = 42",
+            "part_0 = 1",
+            "answer_1 = 1",
+        ] {
+            let result = PuzzleAnswers::parse(&[line]);
+
+            assert!(
+                matches!(result, Err(PuzzleError::InvalidContentError(_))),
+                "line: {}, result: {:?}",
+                line,
+                result
+            );
+        }
+    }
+}
@@ -0,0 +1,345 @@
+use std::{path::PathBuf, time::Duration};
+
+use super::{progress_mode::ProgressMode, puzzle_error::PuzzleError, puzzle_report::ReportFormat};
+
+/// Usage of the puzzle binary printed for `--help`
+const USAGE: &str = "\
+Usage: <puzzle> [OPTIONS]
+
+Options:
+  --input <file>  Read puzzle input from the given file
+  --example       Read puzzle input from resources/example.txt
+  --stdin         Read puzzle input from the standard input
+  --part <n>      Solve only the given part of the puzzle
+  --quiet         Print only the answers
+  --progress <mode>
+                  Display progress bars: auto (default, only in a terminal), always or never
+  --repeat <n>    Solve the puzzle n times
+  --timeout <s>   Give up a part which is not solved within s seconds, cooperative only:
+                  the solver must poll the cancellation token
+  --format <fmt>  Output format of the results: text (default), json or csv
+  --bench <n>     Benchmark the puzzle with n measured runs
+  --warmup <n>    Solve the puzzle n times before the measured runs of the benchmark
+  --baseline <file>
+                  Compare the benchmark with the baseline saved in the file
+  --save-baseline <file>
+                  Save the benchmark as a baseline to the file
+  -h, --help      Print this help";
+
+/// Source of the puzzle input selected on the command line
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum PuzzleInput {
+    /// Default input file _resources/input.txt_ (or _resources/input_N.txt_ per part)
+    #[default]
+    Default,
+
+    /// Example input file _resources/example.txt_ (or _resources/example_N.txt_ per part)
+    Example,
+
+    /// Input file given by path
+    File(PathBuf),
+
+    /// Standard input
+    Stdin,
+}
+
+/// Command line options of the puzzle binary
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleArgs {
+    /// Source of the puzzle input
+    pub input: PuzzleInput,
+
+    /// Solve only this part, all parts are solved if not set
+    pub part: Option<usize>,
+
+    /// Print only the answers
+    pub quiet: bool,
+
+    /// Display progress bars, environment variable _AOC_PROGRESS_ decides if not set
+    pub progress: Option<ProgressMode>,
+
+    /// Number of times the puzzle is solved
+    pub repeat: usize,
+
+    /// Time limit of every part, parts are not limited if not set
+    pub timeout: Option<Duration>,
+
+    /// Output format of the results
+    pub format: ReportFormat,
+
+    /// Number of measured runs of the benchmark, puzzle is not benchmarked if not set
+    pub bench: Option<usize>,
+
+    /// Number of warm-up runs of the benchmark
+    pub warmup: usize,
+
+    /// Baseline file to compare the benchmark with
+    pub baseline: Option<PathBuf>,
+
+    /// File where the benchmark is saved as a new baseline
+    pub save_baseline: Option<PathBuf>,
+
+    /// Print help and exit
+    pub help: bool,
+}
+
+impl Default for PuzzleArgs {
+    fn default() -> Self {
+        Self {
+            input: PuzzleInput::Default,
+            part: None,
+            quiet: false,
+            progress: None,
+            repeat: 1,
+            timeout: None,
+            format: ReportFormat::Text,
+            bench: None,
+            warmup: 0,
+            baseline: None,
+            save_baseline: None,
+            help: false,
+        }
+    }
+}
+
+impl PuzzleArgs {
+    /// Parse command line arguments
+    ///
+    /// # Arguments
+    ///
+    /// _args_ - Command line arguments without the name of the program
+    ///
+    /// # Returns
+    ///
+    /// Parsed options on success, error for unknown or invalid arguments
+    pub fn parse<I, S>(args: I) -> Result<Self, PuzzleError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut result = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_ref() {
+                "--input" => {
+                    let path = Self::value(&mut args, "--input")?;
+                    result.set_input(PuzzleInput::File(PathBuf::from(path)))?;
+                }
+                "--example" => result.set_input(PuzzleInput::Example)?,
+                "--stdin" => result.set_input(PuzzleInput::Stdin)?,
+                "--part" => {
+                    let part = Self::number(&mut args, "--part")?;
+                    result.part = Some(part);
+                }
+                "--quiet" => result.quiet = true,
+                "--progress" => {
+                    let mode = Self::value(&mut args, "--progress")?;
+                    result.progress = Some(ProgressMode::parse(&mode)?);
+                }
+                "--repeat" => result.repeat = Self::number(&mut args, "--repeat")?,
+                "--timeout" => {
+                    let seconds = Self::number(&mut args, "--timeout")?;
+                    result.timeout = Some(Duration::from_secs(seconds as u64));
+                }
+                "--format" => {
+                    let format = Self::value(&mut args, "--format")?;
+                    result.format = Self::format(&format)?;
+                }
+                "--bench" => result.bench = Some(Self::number(&mut args, "--bench")?),
+                "--warmup" => result.warmup = Self::number(&mut args, "--warmup")?,
+                "--baseline" => {
+                    let path = Self::value(&mut args, "--baseline")?;
+                    result.baseline = Some(PathBuf::from(path));
+                }
+                "--save-baseline" => {
+                    let path = Self::value(&mut args, "--save-baseline")?;
+                    result.save_baseline = Some(PathBuf::from(path));
+                }
+                "-h" | "--help" => result.help = true,
+                arg => {
+                    return Err(PuzzleError::InvalidArgumentError(format!(
+                        "Unknown argument '{}'",
+                        arg
+                    )));
+                }
+            }
+        }
+
+        if result.bench.is_none()
+            && (result.warmup > 0 || result.baseline.is_some() || result.save_baseline.is_some())
+        {
+            return Err(PuzzleError::InvalidArgumentError(String::from(
+                "'--warmup', '--baseline' and '--save-baseline' can be used only with '--bench'",
+            )));
+        }
+
+        Ok(result)
+    }
+
+    /// Get usage of the puzzle binary
+    pub fn usage() -> &'static str {
+        USAGE
+    }
+
+    fn set_input(&mut self, input: PuzzleInput) -> Result<(), PuzzleError> {
+        if self.input != PuzzleInput::Default {
+            return Err(PuzzleError::InvalidArgumentError(String::from(
+                "Only one of '--input', '--example' and '--stdin' can be used",
+            )));
+        }
+
+        self.input = input;
+        Ok(())
+    }
+
+    fn value<I, S>(args: &mut I, name: &str) -> Result<String, PuzzleError>
+    where
+        I: Iterator<Item = S>,
+        S: AsRef<str>,
+    {
+        args.next()
+            .map(|value| value.as_ref().to_string())
+            .ok_or_else(|| {
+                PuzzleError::InvalidArgumentError(format!("Missing value of '{}'", name))
+            })
+    }
+
+    fn format(value: &str) -> Result<ReportFormat, PuzzleError> {
+        match value {
+            "text" => Ok(ReportFormat::Text),
+            "json" => Ok(ReportFormat::Json),
+            "csv" => Ok(ReportFormat::Csv),
+            _ => Err(PuzzleError::InvalidArgumentError(format!(
+                "Invalid value '{}' of '--format', expected text, json or csv",
+                value
+            ))),
+        }
+    }
+
+    fn number<I, S>(args: &mut I, name: &str) -> Result<usize, PuzzleError>
+    where
+        I: Iterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let value = Self::value(args, name)?;
+
+        match value.parse::<usize>() {
+            Ok(number) if number > 0 => Ok(number),
+            _ => Err(PuzzleError::InvalidArgumentError(format!(
+                "Invalid value '{}' of '{}', expected positive number",
+                value, name
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_default() {
+        let result = PuzzleArgs::parse(Vec::<String>::new());
+
+        assert!(result.is_ok(), "result: {:?}", result);
+        assert_eq!(result.unwrap(), PuzzleArgs::default());
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let result = PuzzleArgs::parse([
+            "--input", "data.txt", "--part", "2", "--quiet", "--repeat", "5", "--format", "json",
+        ]);
+
+        assert!(result.is_ok(), "result: {:?}", result);
+        assert_eq!(
+            result.unwrap(),
+            PuzzleArgs {
+                input: PuzzleInput::File(PathBuf::from("data.txt")),
+                part: Some(2),
+                quiet: true,
+                repeat: 5,
+                format: ReportFormat::Json,
+                help: false,
+                ..PuzzleArgs::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let result = PuzzleArgs::parse([
+            "--bench",
+            "10",
+            "--warmup",
+            "2",
+            "--baseline",
+            "old.txt",
+            "--save-baseline",
+            "new.txt",
+        ]);
+
+        assert!(result.is_ok(), "result: {:?}", result);
+
+        let result = result.unwrap();
+        assert_eq!(result.bench, Some(10));
+        assert_eq!(result.warmup, 2);
+        assert_eq!(result.baseline, Some(PathBuf::from("old.txt")));
+        assert_eq!(result.save_baseline, Some(PathBuf::from("new.txt")));
+    }
+
+    #[test]
+    fn test_parse_inputs() {
+        let result = PuzzleArgs::parse(["--example"]).unwrap();
+        assert_eq!(result.input, PuzzleInput::Example);
+
+        let result = PuzzleArgs::parse(["--stdin"]).unwrap();
+        assert_eq!(result.input, PuzzleInput::Stdin);
+
+        let result = PuzzleArgs::parse(["--help"]).unwrap();
+        assert!(result.help);
+
+        let result = PuzzleArgs::parse(["--progress", "never"]).unwrap();
+        assert_eq!(result.progress, Some(ProgressMode::Never));
+
+        let result = PuzzleArgs::parse(["--timeout", "30"]).unwrap();
+        assert_eq!(result.timeout, Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_parse_conflicting_inputs() {
+        let result = PuzzleArgs::parse(["--example", "--stdin"]);
+
+        assert!(
+            matches!(result, Err(PuzzleError::InvalidArgumentError(_))),
+            "result: {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for args in [
+            vec!["--unknown"],
+            vec!["--input"],
+            vec!["--part", "x"],
+            vec!["--part", "0"],
+            vec!["--repeat"],
+            vec!["--timeout", "0"],
+            vec!["--format", "xml"],
+            vec!["--progress", "sometimes"],
+            vec!["--bench", "0"],
+            vec!["--warmup", "5"],
+        ] {
+            let result = PuzzleArgs::parse(&args);
+
+            assert!(
+                matches!(result, Err(PuzzleError::InvalidArgumentError(_))),
+                "args: {:?}, result: {:?}",
+                args,
+                result
+            );
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use std::{collections::HashMap, fmt::Display, path::Path, time::Duration};
+
+use super::{puzzle_error::PuzzleError, reader::text_reader::TextReader};
+
+/// Relative slowdown of the median against the baseline which is reported as a regression
+pub const REGRESSION_THRESHOLD: f64 = 0.10;
+
+/// Statistics of the repeated measurement of a single phase
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchmarkStats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchmarkStats {
+    /// Calculates statistics from the measured samples
+    ///
+    /// # Arguments
+    ///
+    /// _samples_ - Measured durations of the phase
+    ///
+    /// # Returns
+    ///
+    /// Statistics of the samples, `None` if there are no samples
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let runs = sorted.len();
+        let median = match runs % 2 {
+            0 => (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2,
+            _ => sorted[runs / 2],
+        };
+
+        let nanos = sorted
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Some(Self {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+/// Benchmark of a single phase of the puzzle, e.g. parsing of the input or solving of a part
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseBenchmark {
+    /// Name of the phase, e.g. `parse`, `parse 1` or `part 1`
+    pub name: String,
+
+    /// Statistics of the phase
+    pub stats: BenchmarkStats,
+}
+
+/// Result of the benchmark of the puzzle, see [super::puzzle::Puzzle::benchmark]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PuzzleBenchmark {
+    /// Description of the puzzle
+    pub description: String,
+
+    /// Benchmarked phases in the order of solving
+    pub phases: Vec<PhaseBenchmark>,
+
+    /// Baseline to compare the phases with
+    pub baseline: Option<BenchmarkBaseline>,
+}
+
+impl PuzzleBenchmark {
+    /// Get benchmark of the phase with the given name
+    pub fn phase(&self, name: &str) -> Option<&PhaseBenchmark> {
+        self.phases.iter().find(|phase| phase.name == name)
+    }
+
+    /// Relative change of the median of the phase against the baseline, e.g. `0.25` if the
+    /// phase is 25 % slower. `None` if there is no baseline for the phase.
+    pub fn change(&self, phase: &PhaseBenchmark) -> Option<f64> {
+        let baseline = self.baseline.as_ref()?.median(&phase.name)?;
+
+        match baseline.is_zero() {
+            true => None,
+            false => Some(phase.stats.median.as_secs_f64() / baseline.as_secs_f64() - 1.0),
+        }
+    }
+
+    /// Get names of the phases which are slower than the baseline more than
+    /// [REGRESSION_THRESHOLD]
+    pub fn regressions(&self) -> Vec<&str> {
+        self.phases
+            .iter()
+            .filter(|phase| {
+                self.change(phase)
+                    .is_some_and(|change| change > REGRESSION_THRESHOLD)
+            })
+            .map(|phase| phase.name.as_str())
+            .collect()
+    }
+
+    fn format_duration(duration: Duration) -> String {
+        format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+    }
+}
+
+impl Display for PuzzleBenchmark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<8} {:>6} {:>12} {:>12} {:>12} {:>12} {:>10}",
+            "Phase", "Runs", "Min", "Median", "Mean", "Stddev", "Baseline"
+        )?;
+
+        for phase in &self.phases {
+            let change = match self.change(phase) {
+                Some(change) if change > REGRESSION_THRESHOLD => {
+                    format!("{:+.1} % REGRESSION", change * 100.0)
+                }
+                Some(change) => format!("{:+.1} %", change * 100.0),
+                None => String::from("-"),
+            };
+
+            writeln!(
+                f,
+                "{:<8} {:>6} {:>12} {:>12} {:>12} {:>12} {:>10}",
+                phase.name,
+                phase.stats.runs,
+                Self::format_duration(phase.stats.min),
+                Self::format_duration(phase.stats.median),
+                Self::format_duration(phase.stats.mean),
+                Self::format_duration(phase.stats.stddev),
+                change
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Medians of the phases saved by a previous benchmark. Baseline is stored in a text file with
+/// a line `<phase> = <median in nanoseconds>` for every phase.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BenchmarkBaseline {
+    medians: HashMap<String, Duration>,
+}
+
+impl BenchmarkBaseline {
+    /// Creates baseline from the medians of the benchmark
+    pub fn new(benchmark: &PuzzleBenchmark) -> Self {
+        Self {
+            medians: benchmark
+                .phases
+                .iter()
+                .map(|phase| (phase.name.clone(), phase.stats.median))
+                .collect(),
+        }
+    }
+
+    /// Get median of the phase, `None` if the phase is not in the baseline
+    pub fn median(&self, name: &str) -> Option<Duration> {
+        self.medians.get(name).copied()
+    }
+
+    /// Loads baseline from the file
+    ///
+    /// # Arguments
+    ///
+    /// _path_ - Path to the baseline file
+    ///
+    /// # Returns
+    ///
+    /// Loaded baseline or error if the file cannot be read or has invalid content
+    pub fn load(path: &Path) -> Result<Self, PuzzleError> {
+        let lines = TextReader::new(path).read_lines()?;
+
+        let medians = lines
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let (name, nanos) = line
+                    .rsplit_once('=')
+                    .and_then(|(name, nanos)| {
+                        nanos.trim().parse::<u64>().ok().map(|nanos| (name, nanos))
+                    })
+                    .ok_or_else(|| {
+                        PuzzleError::InvalidContentError(format!(
+                            "Invalid baseline line '{}', expected '<phase> = <nanoseconds>'",
+                            line
+                        ))
+                    })?;
+
+                Ok((name.trim().to_string(), Duration::from_nanos(nanos)))
+            })
+            .collect::<Result<HashMap<_, _>, PuzzleError>>()?;
+
+        Ok(Self { medians })
+    }
+
+    /// Saves baseline to the file
+    ///
+    /// # Arguments
+    ///
+    /// _path_ - Path to the baseline file, existing file is overwritten
+    ///
+    /// # Returns
+    ///
+    /// Successful result or error if the file cannot be written
+    pub fn save(&self, path: &Path) -> Result<(), PuzzleError> {
+        let mut names = self.medians.keys().collect::<Vec<_>>();
+        names.sort();
+
+        let content = names
+            .iter()
+            .map(|name| format!("{} = {}\n", name, self.medians[*name].as_nanos()))
+            .collect::<String>();
+
+        std::fs::write(path, content)
+            .map_err(|err| PuzzleError::WriteFileError(path.to_string_lossy().to_string(), err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|ms| Duration::from_millis(*ms)).collect()
+    }
+
+    fn build_benchmark(median: u64) -> PuzzleBenchmark {
+        PuzzleBenchmark {
+            description: String::from("Test"),
+            phases: vec![PhaseBenchmark {
+                name: String::from("part 1"),
+                stats: BenchmarkStats::from_samples(&millis(&[median])).unwrap(),
+            }],
+            baseline: None,
+        }
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        assert_eq!(BenchmarkStats::from_samples(&[]), None);
+
+        let stats = BenchmarkStats::from_samples(&millis(&[4, 2, 8, 6])).unwrap();
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(2_236_068));
+
+        let stats = BenchmarkStats::from_samples(&millis(&[3, 1, 2])).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(2));
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = BenchmarkBaseline::new(&build_benchmark(100));
+
+        let mut benchmark = build_benchmark(105);
+        assert!(benchmark.regressions().is_empty());
+
+        benchmark.baseline = Some(baseline.clone());
+        assert!(benchmark.regressions().is_empty());
+        assert!(benchmark.to_string().contains("+5.0 %"));
+
+        let mut benchmark = build_benchmark(120);
+        benchmark.baseline = Some(baseline);
+        assert_eq!(benchmark.regressions(), vec!["part 1"]);
+        assert!(benchmark.to_string().contains("+20.0 % REGRESSION"));
+    }
+
+    #[test]
+    fn test_baseline_save_load() {
+        let path = std::env::temp_dir().join(format!(
+            "advent_of_code_baseline_{}.txt",
+            std::process::id()
+        ));
+        let baseline = BenchmarkBaseline::new(&build_benchmark(42));
+
+        let result = baseline.save(&path);
+        assert!(result.is_ok(), "result: {:?}", result);
+
+        let loaded = BenchmarkBaseline::load(&path);
+        let _ = std::fs::remove_file(&path);
+
+        assert!(loaded.is_ok(), "result: {:?}", loaded);
+        assert_eq!(loaded.unwrap(), baseline);
+        assert_eq!(baseline.median("part 1"), Some(Duration::from_millis(42)));
+    }
+}
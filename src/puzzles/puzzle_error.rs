@@ -1,16 +1,144 @@
-use std::{error::Error, fmt::Display};
+use std::{
+    error::Error,
+    fmt::Display,
+    num::{ParseFloatError, ParseIntError, TryFromIntError},
+    ops::Range,
+    time::Duration,
+};
 
 /// Collection of errors which can arise during solving of the puzzle
 #[derive(Debug)]
 pub enum PuzzleError {
-    OpenFileError(String, std::io::Error), // file name, io error
-    ReadFileError(String, std::io::Error), // file name, io error
-    EmptyFileError(String),                // file name
-    InvalidContentError(String),           // error description
-    GenericError(String),                  // error description
+    OpenFileError(String, std::io::Error),  // file name, io error
+    ReadFileError(String, std::io::Error),  // file name, io error
+    WriteFileError(String, std::io::Error), // file name, io error
+    EmptyFileError(String),                 // file name
+    InvalidContentError(String),            // error description
+    GenericError(String),                   // error description
+    InvalidArgumentError(String),           // error description
+    TimeoutError(Duration),                 // time limit of the part
+    PanicError(String, Option<String>),     // panic message, panic location
+    ParseError(ParseError),                 // position of the error in the input
+    IoError(std::io::Error),                // io error
+    ContextError(String, Box<PuzzleError>), // error description, cause
 }
 
-impl Error for PuzzleError {}
+impl PuzzleError {
+    /// Set name of the input file to the parse error which does not know it yet
+    ///
+    /// # Arguments
+    ///
+    /// _file_ - Name of the input file
+    ///
+    /// # Returns
+    ///
+    /// Same error, parse error with the file name
+    pub(crate) fn with_file(self, file: &str) -> Self {
+        match self {
+            PuzzleError::ParseError(err) if err.file.is_none() => {
+                PuzzleError::ParseError(ParseError {
+                    file: Some(file.to_string()),
+                    ..err
+                })
+            }
+            err => err,
+        }
+    }
+}
+
+impl Error for PuzzleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PuzzleError::OpenFileError(_, err)
+            | PuzzleError::ReadFileError(_, err)
+            | PuzzleError::WriteFileError(_, err)
+            | PuzzleError::IoError(err) => Some(err),
+            PuzzleError::ContextError(_, err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for PuzzleError {
+    fn from(err: std::io::Error) -> Self {
+        PuzzleError::IoError(err)
+    }
+}
+
+impl From<ParseIntError> for PuzzleError {
+    fn from(err: ParseIntError) -> Self {
+        PuzzleError::InvalidContentError(format!("Invalid integer [{}]", err))
+    }
+}
+
+impl From<ParseFloatError> for PuzzleError {
+    fn from(err: ParseFloatError) -> Self {
+        PuzzleError::InvalidContentError(format!("Invalid float [{}]", err))
+    }
+}
+
+impl From<TryFromIntError> for PuzzleError {
+    fn from(err: TryFromIntError) -> Self {
+        PuzzleError::InvalidContentError(format!("Integer out of range [{}]", err))
+    }
+}
+
+/// Extension of [Result] which wraps the error with a description of what failed, the original
+/// error is kept as the source of [PuzzleError::ContextError]
+///
+/// ```
+/// use advent_of_code::puzzles::puzzle_error::{ErrorContext, PuzzleError};
+///
+/// fn parse(line: &str) -> Result<u32, PuzzleError> {
+///     line.parse::<u32>()
+///         .with_context(|| format!("Failed to parse line '{}'", line))
+/// }
+///
+/// assert!(matches!(parse("x"), Err(PuzzleError::ContextError(_, _))));
+/// ```
+pub trait ErrorContext<T> {
+    /// Wrap the error with a description
+    ///
+    /// # Arguments
+    ///
+    /// _context_ - Description of what failed
+    ///
+    /// # Returns
+    ///
+    /// Same value on success, context error with the original error as its cause on failure
+    fn context(self, context: impl Into<String>) -> Result<T, PuzzleError>;
+
+    /// Wrap the error with a description created only on failure
+    ///
+    /// # Arguments
+    ///
+    /// _context_ - Function which creates description of what failed
+    ///
+    /// # Returns
+    ///
+    /// Same value on success, context error with the original error as its cause on failure
+    fn with_context<C, F>(self, context: F) -> Result<T, PuzzleError>
+    where
+        C: Into<String>,
+        F: FnOnce() -> C;
+}
+
+impl<T, E> ErrorContext<T> for Result<T, E>
+where
+    E: Into<PuzzleError>,
+{
+    fn context(self, context: impl Into<String>) -> Result<T, PuzzleError> {
+        self.map_err(|err| PuzzleError::ContextError(context.into(), Box::new(err.into())))
+    }
+
+    fn with_context<C, F>(self, context: F) -> Result<T, PuzzleError>
+    where
+        C: Into<String>,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|err| PuzzleError::ContextError(context().into(), Box::new(err.into())))
+    }
+}
 
 impl Display for PuzzleError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -21,13 +149,277 @@ impl Display for PuzzleError {
             PuzzleError::ReadFileError(file_name, err) => {
                 format!("Read file error '{}' [{}]", file_name, err)
             }
+            PuzzleError::WriteFileError(file_name, err) => {
+                format!("Write file error '{}' [{}]", file_name, err)
+            }
             PuzzleError::EmptyFileError(file_name) => {
                 format!("Empty file error '{}'", file_name)
             }
             PuzzleError::InvalidContentError(err) => err.to_string(),
             PuzzleError::GenericError(err) => err.to_string(),
+            PuzzleError::InvalidArgumentError(err) => {
+                format!("Invalid argument error [{}]", err)
+            }
+            PuzzleError::TimeoutError(timeout) => {
+                format!("Timeout error [time limit {:?} exceeded]", timeout)
+            }
+            PuzzleError::PanicError(message, Some(location)) => {
+                format!("Panic error '{}' [{}]", message, location)
+            }
+            PuzzleError::PanicError(message, None) => format!("Panic error '{}'", message),
+            PuzzleError::ParseError(err) => err.to_string(),
+            PuzzleError::IoError(err) => format!("IO error [{}]", err),
+            PuzzleError::ContextError(context, err) => format!("{} [{}]", context, err),
         };
 
         write!(f, "{}", msg)
     }
 }
+
+/// Error in the input with its position, displayed with the offending line and the columns
+/// underlined by carets, see [InputLine](super::input_line::InputLine)
+///
+/// ```text
+/// Parse error 'invalid digit found in string' at input.txt:3:4
+///   |
+/// 3 | 12 ab 4
+///   |    ^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Description of the error
+    pub message: String,
+
+    /// Name of the input file, filled in by [Puzzle](super::puzzle::Puzzle) if not set
+    pub file: Option<String>,
+
+    /// Number of the line starting from 1
+    pub line: usize,
+
+    /// Byte range of the offending part of the line
+    pub columns: Range<usize>,
+
+    /// Content of the offending line
+    pub content: String,
+}
+
+impl ParseError {
+    /// Get columns clamped to the content and widened to the char boundaries
+    fn char_columns(&self) -> Range<usize> {
+        let end = self.columns.end.min(self.content.len());
+        let mut start = self.columns.start.min(end);
+        let mut end = end;
+
+        while !self.content.is_char_boundary(start) {
+            start -= 1;
+        }
+
+        while !self.content.is_char_boundary(end) {
+            end += 1;
+        }
+
+        start..end
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Columns are displayed in characters, invalid columns are clamped to the line
+        let columns = self.char_columns();
+        let start = self.content[..columns.start].chars().count();
+        let width = self.content[columns].chars().count().max(1);
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(
+            f,
+            "Parse error '{}' at {}:{}:{}",
+            self.message,
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            start + 1
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.content)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(start), "^".repeat(width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error() -> ParseError {
+        ParseError {
+            message: String::from("invalid digit found in string"),
+            file: None,
+            line: 12,
+            columns: 3..5,
+            content: String::from("12 ab 4"),
+        }
+    }
+
+    #[test]
+    fn test_source() {
+        let err = PuzzleError::ReadFileError(
+            String::from("input.txt"),
+            std::io::Error::other("Disk failure"),
+        );
+        assert_eq!(
+            err.source().map(|err| err.to_string()).as_deref(),
+            Some("Disk failure")
+        );
+
+        let err = PuzzleError::GenericError(String::from("Error"));
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn test_from() {
+        let err = PuzzleError::from("x".parse::<u32>().unwrap_err());
+        assert!(
+            matches!(err, PuzzleError::InvalidContentError(_)),
+            "err: {:?}",
+            err
+        );
+
+        let err = PuzzleError::from("x".parse::<f64>().unwrap_err());
+        assert!(
+            matches!(err, PuzzleError::InvalidContentError(_)),
+            "err: {:?}",
+            err
+        );
+
+        let err = PuzzleError::from(u8::try_from(300_u32).unwrap_err());
+        assert!(
+            matches!(err, PuzzleError::InvalidContentError(_)),
+            "err: {:?}",
+            err
+        );
+
+        let err = PuzzleError::from(std::io::Error::other("Disk failure"));
+        assert!(matches!(err, PuzzleError::IoError(_)), "err: {:?}", err);
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn test_context() {
+        let result: Result<u32, _> = "7".parse::<u32>().context("Failed to parse");
+        assert!(matches!(result, Ok(7)), "result: {:?}", result);
+
+        let err = "x"
+            .parse::<u32>()
+            .with_context(|| format!("Failed to parse line {}", 3))
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse line 3 [Invalid integer [invalid digit found in string]]"
+        );
+
+        // Cause of the error is available as its source
+        let source = err.source().expect("Missing source");
+        assert_eq!(
+            source.to_string(),
+            "Invalid integer [invalid digit found in string]"
+        );
+
+        // Context can be added to the puzzle error as well
+        let err = Err::<(), _>(err)
+            .context("Failed to parse input")
+            .unwrap_err();
+        assert!(
+            matches!(&err, PuzzleError::ContextError(_, cause) if matches!(cause.as_ref(), PuzzleError::ContextError(_, _))),
+            "err: {:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        assert_eq!(
+            parse_error().to_string(),
+            "Parse error 'invalid digit found in string' at <input>:12:4\n   |\n12 | 12 ab 4\n   |    ^^"
+        );
+    }
+
+    #[test]
+    fn test_parse_error_out_of_range() {
+        let err = ParseError {
+            columns: 2..10,
+            content: String::from("abc"),
+            ..parse_error()
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "Parse error 'invalid digit found in string' at <input>:12:3\n   |\n12 | abc\n   |   ^"
+        );
+
+        let err = ParseError {
+            columns: Range { start: 7, end: 5 },
+            content: String::from("abc"),
+            ..parse_error()
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "Parse error 'invalid digit found in string' at <input>:12:4\n   |\n12 | abc\n   |    ^"
+        );
+    }
+
+    #[test]
+    fn test_parse_error_char_boundary() {
+        let err = ParseError {
+            columns: 1..2,
+            content: String::from("\u{00e9}x"),
+            ..parse_error()
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "Parse error 'invalid digit found in string' at <input>:12:1\n   |\n12 | \u{00e9}x\n   | ^"
+        );
+    }
+
+    #[test]
+    fn test_parse_error_unicode() {
+        let err = ParseError {
+            message: String::from("Unknown symbol"),
+            file: Some(String::from("input.txt")),
+            line: 1,
+            columns: 4..5,
+            content: String::from("\u{00e9}\u{00e9}x"),
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "Parse error 'Unknown symbol' at input.txt:1:3\n  |\n1 | \u{00e9}\u{00e9}x\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn test_with_file() {
+        let err = PuzzleError::ParseError(parse_error()).with_file("input.txt");
+        assert!(
+            matches!(&err, PuzzleError::ParseError(err) if err.file.as_deref() == Some("input.txt")),
+            "err: {:?}",
+            err
+        );
+
+        // File name already known is kept
+        let err = err.with_file("other.txt");
+        assert!(
+            matches!(&err, PuzzleError::ParseError(err) if err.file.as_deref() == Some("input.txt")),
+            "err: {:?}",
+            err
+        );
+
+        // Other errors are not changed
+        let err = PuzzleError::GenericError(String::from("Error")).with_file("input.txt");
+        assert!(
+            matches!(err, PuzzleError::GenericError(_)),
+            "err: {:?}",
+            err
+        );
+    }
+}
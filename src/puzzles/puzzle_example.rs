@@ -0,0 +1,165 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use crate::env::project::Project;
+
+use super::answer::Answer;
+
+/// Source of the example input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ExampleInput {
+    /// Example written directly in the test
+    Inline(String),
+
+    /// Example stored in a file
+    File(PathBuf),
+}
+
+/// Example from the puzzle text together with its expected answers, see
+/// [super::puzzle_tester::PuzzleTester::test_example]. Expected answer is given only for the
+/// parts the example applies to.
+///
+/// ```
+/// use advent_of_code::puzzles::puzzle_example::PuzzleExample;
+///
+/// let example = PuzzleExample::new("1abc2\npqr3stu8vwx").named("digits").part(1, 50_u32);
+/// let part_2 = PuzzleExample::from_file("example_2.txt").part(2, "281");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleExample {
+    name: Option<String>,
+    input: ExampleInput,
+    solutions: BTreeMap<usize, Answer>,
+}
+
+impl PuzzleExample {
+    /// Creates an example with inline input
+    ///
+    /// # Arguments
+    ///
+    /// _input_ - Input of the example, lines are separated by `\n`
+    ///
+    /// # Returns
+    ///
+    /// Example without any expected answer
+    pub fn new(input: &str) -> Self {
+        Self {
+            name: None,
+            input: ExampleInput::Inline(input.to_string()),
+            solutions: BTreeMap::new(),
+        }
+    }
+
+    /// Creates an example with input stored in the project _resources_ directory
+    ///
+    /// # Arguments
+    ///
+    /// _file_name_ - Name of the file in the _resources_ directory, e.g. `example_1.txt`
+    ///
+    /// # Returns
+    ///
+    /// Example without any expected answer
+    pub fn from_file(file_name: &str) -> Self {
+        Self {
+            name: Some(file_name.to_string()),
+            input: ExampleInput::File(Project::new().resource_file(file_name)),
+            solutions: BTreeMap::new(),
+        }
+    }
+
+    /// Set the expected answer of the part
+    ///
+    /// # Arguments
+    ///
+    /// _part_ - Number of the part starting from 1
+    /// _solution_ - Expected answer of the part for this example
+    ///
+    /// # Returns
+    ///
+    /// Updated example
+    pub fn part(mut self, part: usize, solution: impl Into<Answer>) -> Self {
+        self.solutions.insert(part, solution.into());
+        self
+    }
+
+    /// Set name of the example used in failure messages, inline example is named
+    /// `inline example` and example stored in a file is named by the file by default
+    ///
+    /// # Arguments
+    ///
+    /// _name_ - Name of the example
+    ///
+    /// # Returns
+    ///
+    /// Updated example
+    pub fn named(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Set name of the example only if it has no name yet, used by
+    /// [puzzle_tests](crate::puzzle_tests) to name inline examples by their tests
+    #[doc(hidden)]
+    pub fn or_named(self, name: &str) -> Self {
+        match self.name {
+            Some(_) => self,
+            None => self.named(name),
+        }
+    }
+
+    /// Get name of the example used in failure messages
+    pub fn get_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("inline example")
+    }
+
+    /// Get expected answers of the parts ordered by parts
+    pub fn solutions(&self) -> impl Iterator<Item = (usize, &Answer)> {
+        self.solutions
+            .iter()
+            .map(|(part, solution)| (*part, solution))
+    }
+
+    pub(crate) fn input(&self) -> &ExampleInput {
+        &self.input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let example = PuzzleExample::new("1\n2").part(2, 3_u32).part(1, "1");
+
+        assert_eq!(example.get_name(), "inline example");
+        assert_eq!(example.input(), &ExampleInput::Inline(String::from("1\n2")));
+        assert_eq!(
+            example.solutions().collect::<Vec<_>>(),
+            vec![(1, &Answer::from(1_u32)), (2, &Answer::from("3"))]
+        );
+    }
+
+    #[test]
+    fn test_example_from_file() {
+        let example = PuzzleExample::from_file("example.txt").part(1, "42");
+
+        assert_eq!(example.get_name(), "example.txt");
+        assert_eq!(
+            example.input(),
+            &ExampleInput::File(Project::new().resource_file("example.txt"))
+        );
+    }
+
+    #[test]
+    fn test_example_named() {
+        let example = PuzzleExample::new("1").named("first");
+        assert_eq!(example.get_name(), "first");
+        assert_eq!(example.or_named("second").get_name(), "first");
+
+        let example = PuzzleExample::new("1").or_named("second");
+        assert_eq!(example.get_name(), "second");
+
+        let example = PuzzleExample::from_file("example.txt").or_named("second");
+        assert_eq!(example.get_name(), "example.txt");
+    }
+}
@@ -0,0 +1,78 @@
+/// Generates tests of the puzzle solver into a `puzzle_tests` module, replacing the hand written
+/// `LazyLock<PuzzleTester<Solver>>`, `get_tester()` and `#[test]` functions. Input file is parsed
+/// once and shared by the tests of all parts, therefore the solver has to be `Sync`.
+///
+/// # Arguments
+///
+/// _solver_ - Type of the puzzle solver implementing [PuzzleSolver](crate::puzzles::puzzle_solver::PuzzleSolver)
+/// _part_N_ - Optional expected answer of the part N for the input file (any value convertible
+/// to [Answer](crate::puzzles::answer::Answer)), test `part_N` is generated only for parts with
+/// an expected answer
+/// _examples_ - Optional list of `name => example` pairs, test `name` is generated for every
+/// [PuzzleExample](crate::puzzles::puzzle_example::PuzzleExample), inline example without a name
+/// is named by its test in failure messages
+///
+/// ```ignore
+/// advent_of_code::puzzle_tests!(
+///     Solver,
+///     part_1 = 54390_u32,
+///     part_2 = "54277",
+///     examples = [
+///         example_1 => PuzzleExample::new("1abc2\npqr3stu8vwx").part(1, 50_u32),
+///         example_2 => PuzzleExample::from_file("example_2.txt").part(2, "281"),
+///     ]
+/// );
+/// ```
+#[macro_export]
+macro_rules! puzzle_tests {
+    (@part $name:ident, $test:ident, $solution:expr) => {
+        #[test]
+        fn $name() {
+            get_tester().$test();
+        }
+    };
+
+    (
+        $solver:ty
+        $(, part_1 = $part_1:expr)?
+        $(, part_2 = $part_2:expr)?
+        $(, part_3 = $part_3:expr)?
+        $(, examples = [$($name:ident => $example:expr),* $(,)?])?
+        $(,)?
+    ) => {
+        #[cfg(test)]
+        mod puzzle_tests {
+            #[allow(unused_imports)]
+            use super::*;
+            #[allow(unused_imports)]
+            use $crate::puzzles::{puzzle_example::PuzzleExample, puzzle_tester::PuzzleTester};
+
+            #[allow(dead_code)]
+            fn get_tester() -> &'static PuzzleTester<$solver> {
+                static TESTER: std::sync::LazyLock<PuzzleTester<$solver>> =
+                    std::sync::LazyLock::new(|| {
+                        PuzzleTester::new_with_solutions(vec![
+                            $((1, $crate::puzzles::answer::Answer::from($part_1)),)?
+                            $((2, $crate::puzzles::answer::Answer::from($part_2)),)?
+                            $((3, $crate::puzzles::answer::Answer::from($part_3)),)?
+                        ])
+                    });
+
+                &TESTER
+            }
+
+            $($crate::puzzle_tests!(@part part_1, test_part_1, $part_1);)?
+
+            $($crate::puzzle_tests!(@part part_2, test_part_2, $part_2);)?
+
+            $($crate::puzzle_tests!(@part part_3, test_part_3, $part_3);)?
+
+            $($(
+                #[test]
+                fn $name() {
+                    PuzzleTester::<$solver>::test_example(&$example.or_named(stringify!($name)));
+                }
+            )*)?
+        }
+    };
+}
@@ -0,0 +1,415 @@
+use std::time::Duration;
+
+use super::{answer::Answer, puzzle::PuzzleResult, puzzle_error::PuzzleError};
+
+/// Header of the CSV report
+const CSV_HEADER: &str = "description,phase,part,value,expected,duration_ns,error";
+
+/// Output format of the puzzle report
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Human readable console output
+    #[default]
+    Text,
+
+    /// JSON object per solved puzzle
+    Json,
+
+    /// CSV rows with a single header
+    Csv,
+}
+
+/// Report of reading and parsing of a single input file
+#[derive(Debug)]
+pub struct InputReport {
+    /// Part which the input belongs to, `None` if the input is shared by all parts
+    pub part: Option<usize>,
+
+    /// Path to the input file, `None` if puzzle has no input file
+    pub input: Option<String>,
+
+    /// Time spent by reading and parsing of the input
+    pub duration: Duration,
+
+    /// Error occurred during reading or parsing of the input
+    pub error: Option<PuzzleError>,
+}
+
+/// Report of solving of a single part of the puzzle
+#[derive(Debug)]
+pub struct PartReport {
+    /// Number of the part starting from 1
+    pub part: usize,
+
+    /// Answer of the part, `None` if the part failed
+    pub answer: Option<Answer>,
+
+    /// Known answer of the part, `None` if the answer is not known
+    pub expected: Option<String>,
+
+    /// Time spent by solving of the part
+    pub duration: Duration,
+
+    /// Error occurred during solving of the part
+    pub error: Option<PuzzleError>,
+}
+
+impl PartReport {
+    /// Check the answer against the known answer
+    ///
+    /// # Returns
+    ///
+    /// `Some(true)` if the answer matches the known answer, `Some(false)` if it does not match
+    /// or the part failed and `None` if the answer of the part is not known
+    pub fn is_correct(&self) -> Option<bool> {
+        self.expected
+            .as_ref()
+            .map(|expected| self.answer.as_ref() == Some(&Answer::from(expected)))
+    }
+}
+
+/// Structured report of the solved puzzle which can be exported to JSON or CSV
+#[derive(Debug, Default)]
+pub struct PuzzleReport {
+    /// Description of the puzzle
+    pub description: String,
+
+    /// Reports of all read input files in the order of reading
+    pub inputs: Vec<InputReport>,
+
+    /// Reports of all solved parts in the order of solving
+    pub parts: Vec<PartReport>,
+}
+
+impl PuzzleReport {
+    /// Creates new empty report
+    pub fn new(description: &str) -> Self {
+        Self {
+            description: description.to_string(),
+            inputs: Vec::new(),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Get report of the given part, `None` if the part was not solved
+    pub fn part(&self, part: usize) -> Option<&PartReport> {
+        self.parts.iter().find(|report| report.part == part)
+    }
+
+    /// Get answer of the given part, `None` if the part was not solved or failed
+    pub fn answer(&self, part: usize) -> Option<&Answer> {
+        self.part(part).and_then(|report| report.answer.as_ref())
+    }
+
+    /// Get all errors occurred while solving the puzzle
+    pub fn errors(&self) -> impl Iterator<Item = &PuzzleError> {
+        self.inputs
+            .iter()
+            .filter_map(|report| report.error.as_ref())
+            .chain(self.parts.iter().filter_map(|report| report.error.as_ref()))
+    }
+
+    /// Get reports of the parts whose answer does not match the known answer
+    pub fn wrong_answers(&self) -> impl Iterator<Item = &PartReport> {
+        self.parts
+            .iter()
+            .filter(|report| report.error.is_none() && report.is_correct() == Some(false))
+    }
+
+    /// Check if the puzzle was solved without any error and all known answers match
+    pub fn is_ok(&self) -> bool {
+        self.errors().next().is_none() && self.wrong_answers().next().is_none()
+    }
+
+    /// Convert report to the result of the puzzle
+    ///
+    /// # Returns
+    ///
+    /// First error occurred while solving the puzzle or first answer which does not match the
+    /// known answer, empty result if there was no error
+    pub fn into_result(self) -> PuzzleResult {
+        let wrong_answer = self.wrong_answers().next().map(|report| {
+            PuzzleError::GenericError(format!(
+                "Part {} answer '{}' does not match known answer '{}'",
+                report.part,
+                report
+                    .answer
+                    .as_ref()
+                    .map(|answer| answer.to_string())
+                    .unwrap_or_default(),
+                report.expected.as_deref().unwrap_or_default()
+            ))
+        });
+
+        let error = self
+            .inputs
+            .into_iter()
+            .filter_map(|report| report.error)
+            .chain(self.parts.into_iter().filter_map(|report| report.error))
+            .chain(wrong_answer)
+            .next();
+
+        match error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Export report to JSON
+    pub fn to_json(&self) -> String {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|report| {
+                format!(
+                    "{{\"part\":{},\"input\":{},\"duration_ns\":{},\"error\":{}}}",
+                    Self::json_number(report.part),
+                    Self::json_string(report.input.as_deref()),
+                    report.duration.as_nanos(),
+                    Self::json_error(&report.error),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let parts = self
+            .parts
+            .iter()
+            .map(|report| {
+                format!(
+                    "{{\"part\":{},\"answer\":{},\"expected\":{},\"correct\":{},\"duration_ns\":{},\"error\":{}}}",
+                    report.part,
+                    Self::json_answer(report.answer.as_ref()),
+                    Self::json_string(report.expected.as_deref()),
+                    match report.is_correct() {
+                        Some(correct) => correct.to_string(),
+                        None => String::from("null"),
+                    },
+                    report.duration.as_nanos(),
+                    Self::json_error(&report.error),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        format!(
+            "{{\"description\":{},\"inputs\":[{}],\"parts\":[{}]}}",
+            Self::json_string(Some(&self.description)),
+            inputs.join(","),
+            parts.join(",")
+        )
+    }
+
+    /// Get header of the CSV report, see [PuzzleReport::to_csv_rows]
+    pub fn csv_header() -> &'static str {
+        CSV_HEADER
+    }
+
+    /// Export report to CSV rows without the header, one row for every input and part
+    pub fn to_csv_rows(&self) -> String {
+        let inputs = self.inputs.iter().map(|report| {
+            [
+                Self::csv_field(&self.description),
+                String::from("input"),
+                report.part.map(|part| part.to_string()).unwrap_or_default(),
+                Self::csv_field(report.input.as_deref().unwrap_or_default()),
+                String::new(),
+                report.duration.as_nanos().to_string(),
+                Self::csv_error(&report.error),
+            ]
+            .join(",")
+        });
+
+        let parts = self.parts.iter().map(|report| {
+            [
+                Self::csv_field(&self.description),
+                String::from("part"),
+                report.part.to_string(),
+                Self::csv_field(
+                    &report
+                        .answer
+                        .as_ref()
+                        .map(|answer| answer.to_string())
+                        .unwrap_or_default(),
+                ),
+                Self::csv_field(report.expected.as_deref().unwrap_or_default()),
+                report.duration.as_nanos().to_string(),
+                Self::csv_error(&report.error),
+            ]
+            .join(",")
+        });
+
+        inputs.chain(parts).collect::<Vec<_>>().join("\n")
+    }
+
+    /// Export report to CSV including the header
+    pub fn to_csv(&self) -> String {
+        format!("{}\n{}", CSV_HEADER, self.to_csv_rows())
+    }
+
+    fn json_number(value: Option<usize>) -> String {
+        match value {
+            Some(value) => value.to_string(),
+            None => String::from("null"),
+        }
+    }
+
+    fn json_string(value: Option<&str>) -> String {
+        let value = match value {
+            Some(value) => value,
+            None => return String::from("null"),
+        };
+
+        let mut result = String::with_capacity(value.len() + 2);
+        result.push('"');
+
+        for c in value.chars() {
+            match c {
+                '"' => result.push_str("\\\""),
+                '\\' => result.push_str("\\\\"),
+                '\n' => result.push_str("\\n"),
+                '\r' => result.push_str("\\r"),
+                '\t' => result.push_str("\\t"),
+                c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+                c => result.push(c),
+            }
+        }
+
+        result.push('"');
+        result
+    }
+
+    fn json_answer(answer: Option<&Answer>) -> String {
+        match answer {
+            Some(answer) if answer.is_number() => answer.to_string(),
+            Some(answer) => Self::json_string(Some(&answer.to_string())),
+            None => String::from("null"),
+        }
+    }
+
+    fn json_error(error: &Option<PuzzleError>) -> String {
+        Self::json_string(error.as_ref().map(|err| err.to_string()).as_deref())
+    }
+
+    fn csv_field(value: &str) -> String {
+        match value.contains([',', '"', '\n', '\r']) {
+            true => format!("\"{}\"", value.replace('"', "\"\"")),
+            false => value.to_string(),
+        }
+    }
+
+    fn csv_error(error: &Option<PuzzleError>) -> String {
+        error
+            .as_ref()
+            .map(|err| Self::csv_field(&err.to_string()))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_report() -> PuzzleReport {
+        let mut report = PuzzleReport::new("--- Day 1: \"Test\" ---");
+
+        report.inputs.push(InputReport {
+            part: None,
+            input: Some(String::from("input.txt")),
+            duration: Duration::from_nanos(1500),
+            error: None,
+        });
+        report.parts.push(PartReport {
+            part: 1,
+            answer: Some(Answer::from(42_u32)),
+            expected: Some(String::from("42")),
+            duration: Duration::from_nanos(2000),
+            error: None,
+        });
+        report.parts.push(PartReport {
+            part: 2,
+            answer: None,
+            expected: None,
+            duration: Duration::from_nanos(10),
+            error: Some(PuzzleError::GenericError(String::from("Failed, badly"))),
+        });
+
+        report
+    }
+
+    #[test]
+    fn test_answer() {
+        let report = build_report();
+
+        assert_eq!(report.answer(1), Some(&Answer::from(42_u32)));
+        assert_eq!(report.answer(2), None);
+        assert_eq!(report.answer(3), None);
+        assert!(!report.is_ok());
+        assert_eq!(report.errors().count(), 1);
+    }
+
+    #[test]
+    fn test_wrong_answer() {
+        let mut report = PuzzleReport::new("Test");
+        report.parts.push(PartReport {
+            part: 1,
+            answer: Some(Answer::from(41_u32)),
+            expected: Some(String::from("42")),
+            duration: Duration::ZERO,
+            error: None,
+        });
+
+        assert_eq!(report.parts[0].is_correct(), Some(false));
+        assert_eq!(report.wrong_answers().count(), 1);
+        assert!(!report.is_ok());
+
+        let result = report.into_result();
+        assert!(
+            matches!(result, Err(PuzzleError::GenericError(_))),
+            "result: {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_into_result() {
+        let result = build_report().into_result();
+        assert!(
+            matches!(result, Err(PuzzleError::GenericError(_))),
+            "result: {:?}",
+            result
+        );
+
+        let result = PuzzleReport::new("Empty").into_result();
+        assert!(result.is_ok(), "result: {:?}", result);
+    }
+
+    #[test]
+    fn test_to_json() {
+        let report = build_report();
+
+        assert_eq!(
+            report.to_json(),
+            concat!(
+                r#"{"description":"--- Day 1: \"Test\" ---","#,
+                r#""inputs":[{"part":null,"input":"input.txt","duration_ns":1500,"error":null}],"#,
+                r#""parts":[{"part":1,"answer":42,"expected":"42","correct":true,"duration_ns":2000,"error":null},"#,
+                r#"{"part":2,"answer":null,"expected":null,"correct":null,"duration_ns":10,"error":"Failed, badly"}]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let report = build_report();
+
+        assert_eq!(
+            report.to_csv(),
+            [
+                "description,phase,part,value,expected,duration_ns,error",
+                r#""--- Day 1: ""Test"" ---",input,,input.txt,,1500,"#,
+                r#""--- Day 1: ""Test"" ---",part,1,42,42,2000,"#,
+                r#""--- Day 1: ""Test"" ---",part,2,,,10,"Failed, badly""#,
+            ]
+            .join("\n")
+        );
+    }
+}
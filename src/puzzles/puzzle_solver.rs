@@ -1,6 +1,11 @@
-use super::puzzle::{PuzzleResult, SolutionResult};
+use super::{
+    answer::Answer,
+    puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
+};
 
-/// Defines requirements for real puzzle solver which can be used to solve Advent of Code puzzles.
+/// Defines requirements for real puzzle solver which can be used to solve Advent of Code puzzles
+/// and Everybody Codes quests.
 pub trait PuzzleSolver {
     /// Creates a new instance of puzzle solver
     ///
@@ -16,8 +21,20 @@ pub trait PuzzleSolver {
     /// String representation of puzzle description
     fn get_description(&self) -> &str;
 
+    /// Get number of parts of the puzzle, Advent of Code puzzles have two parts and Everybody
+    /// Codes quests have three parts
+    ///
+    /// # Returns
+    ///
+    /// Number of parts of the puzzle
+    fn get_parts_count(&self) -> usize {
+        2
+    }
+
     /// Parse content of the input file
-    /// This method is called only if [Puzzle] is created with reader
+    /// This method is called only if [Puzzle] is created with reader. Lines can be iterated by
+    /// [InputLine::iter](super::input_line::InputLine::iter) to report parse errors with their
+    /// position in the input file.
     ///
     /// # Arguments
     ///
@@ -28,21 +45,133 @@ pub trait PuzzleSolver {
     /// Empty result on success, error on failure
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult;
 
+    /// Parse content of the input file of the given part
+    /// This method is called only if [Puzzle] is created with a reader for every part, right
+    /// before the part is solved. Default implementation forwards to [PuzzleSolver::parse_input_file].
+    ///
+    /// # Arguments
+    ///
+    /// _part_ - Number of the part starting from 1
+    /// _lines_ - Lines read from input file of the part
+    ///
+    /// # Returns
+    ///
+    /// Empty result on success, error on failure
+    fn parse_input_part(&mut self, _part: usize, lines: &[&str]) -> PuzzleResult {
+        self.parse_input_file(lines)
+    }
+
     /// Solve part 1 of the puzzle
     ///
     /// # Returns
     ///
-    /// String representation of the solution on success, error on failure
+    /// Answer of the part on success, error on failure
     fn part_1(&self) -> SolutionResult {
-        Ok(String::from("Not solved"))
+        Ok(Answer::from("Not solved"))
     }
 
     /// Solve part 2 of the puzzle
     ///
     /// # Returns
     ///
-    /// String representation of the solution on success, error on failure
+    /// Answer of the part on success, error on failure
     fn part_2(&self) -> SolutionResult {
-        Ok(String::from("Not solved"))
+        Ok(Answer::from("Not solved"))
+    }
+
+    /// Solve part 3 of the puzzle, used only if [PuzzleSolver::get_parts_count] returns 3
+    ///
+    /// # Returns
+    ///
+    /// Answer of the part on success, error on failure
+    fn part_3(&self) -> SolutionResult {
+        Ok(Answer::from("Not solved"))
+    }
+
+    /// Check if the parts share state, i.e. a part continues from the state left by the previous
+    /// parts (e.g. a simulation). Such puzzle is solved by the `part_N_mut` methods and the
+    /// previous parts are always solved before the requested part.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the parts share state, `false` by default
+    fn parts_share_state(&self) -> bool {
+        false
+    }
+
+    /// Solve part 1 of the puzzle with mutable access to the solver, called only if
+    /// [PuzzleSolver::parts_share_state] returns `true`. Default implementation delegates to
+    /// [PuzzleSolver::part_1].
+    ///
+    /// # Returns
+    ///
+    /// Answer of the part on success, error on failure
+    fn part_1_mut(&mut self) -> SolutionResult {
+        self.part_1()
+    }
+
+    /// Solve part 2 of the puzzle with mutable access to the solver, called only if
+    /// [PuzzleSolver::parts_share_state] returns `true`. Default implementation delegates to
+    /// [PuzzleSolver::part_2].
+    ///
+    /// # Returns
+    ///
+    /// Answer of the part on success, error on failure
+    fn part_2_mut(&mut self) -> SolutionResult {
+        self.part_2()
+    }
+
+    /// Solve part 3 of the puzzle with mutable access to the solver, called only if
+    /// [PuzzleSolver::parts_share_state] returns `true`. Default implementation delegates to
+    /// [PuzzleSolver::part_3].
+    ///
+    /// # Returns
+    ///
+    /// Answer of the part on success, error on failure
+    fn part_3_mut(&mut self) -> SolutionResult {
+        self.part_3()
+    }
+
+    /// Solve the given part of the puzzle
+    ///
+    /// # Arguments
+    ///
+    /// _part_ - Number of the part starting from 1
+    ///
+    /// # Returns
+    ///
+    /// Answer of the part on success, error on failure
+    fn solve_part(&self, part: usize) -> SolutionResult {
+        match part {
+            1 => self.part_1(),
+            2 => self.part_2(),
+            3 => self.part_3(),
+            _ => Err(PuzzleError::GenericError(format!(
+                "Puzzle does not have part {}",
+                part
+            ))),
+        }
+    }
+
+    /// Solve the given part of the puzzle with mutable access to the solver, used only for parts
+    /// sharing state, see [PuzzleSolver::parts_share_state]
+    ///
+    /// # Arguments
+    ///
+    /// _part_ - Number of the part starting from 1
+    ///
+    /// # Returns
+    ///
+    /// Answer of the part on success, error on failure
+    fn solve_part_mut(&mut self, part: usize) -> SolutionResult {
+        match part {
+            1 => self.part_1_mut(),
+            2 => self.part_2_mut(),
+            3 => self.part_3_mut(),
+            _ => Err(PuzzleError::GenericError(format!(
+                "Puzzle does not have part {}",
+                part
+            ))),
+        }
     }
 }
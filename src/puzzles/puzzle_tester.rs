@@ -1,83 +1,396 @@
-use std::{fs::File, io::BufRead, io::BufReader, sync::Mutex};
+use std::{
+    fs::File,
+    io::BufRead,
+    io::BufReader,
+    path::{Path, PathBuf},
+};
 
 use crate::env::project::Project;
 
-use super::puzzle_solver::PuzzleSolver;
+use super::{
+    answer::Answer,
+    panic_capture::catch_panic,
+    puzzle::SolutionResult,
+    puzzle_answers::PuzzleAnswers,
+    puzzle_example::{ExampleInput, PuzzleExample},
+    puzzle_solver::PuzzleSolver,
+};
 
+/// Tests the puzzle solver against the expected answers. Input file is parsed once when the
+/// tester is created and the parsed solver is shared by the tests of all parts. Parts are solved
+/// through a shared reference so a panic in one part does not affect the tests of other parts.
+/// Tester can be stored in a `static` (e.g. `LazyLock`) if the solver is `Sync`.
+/// If the parts share state (see [PuzzleSolver::parts_share_state]) all parts are solved in
+/// order by a single solver when the tester is created, the same way as [Puzzle](super::puzzle::Puzzle)
+/// solves them, and the tests check the recorded answers.
 pub struct PuzzleTester<T>
 where
     T: PuzzleSolver,
 {
-    solver: Mutex<T>,
-    solution_1: String,
-    solution_2: String,
+    solvers: Vec<T>,
+    shared_results: Vec<SolutionResult>,
+    solutions: Vec<Option<Answer>>,
 }
 
 impl<T> PuzzleTester<T>
 where
     T: PuzzleSolver,
 {
-    pub fn new(solution_1: &str, solution_2: &str) -> Self {
-        let solver = Self::create_solver();
-        Self {
-            solver,
-            solution_1: String::from(solution_1),
-            solution_2: String::from(solution_2),
+    /// Create a tester for a puzzle with two parts. Part uses its own input file _input_N.txt_
+    /// if it exists, otherwise shared input file _input.txt_ is used.
+    pub fn new(solution_1: impl Into<Answer>, solution_2: impl Into<Answer>) -> Self {
+        Self::with_solutions(vec![Some(solution_1.into()), Some(solution_2.into())])
+    }
+
+    /// Create a tester for a puzzle with three parts (e.g. Everybody Codes quests). Part uses its
+    /// own input file _input_N.txt_ if it exists, otherwise shared input file _input.txt_ is used.
+    pub fn new_with_part_3(
+        solution_1: impl Into<Answer>,
+        solution_2: impl Into<Answer>,
+        solution_3: impl Into<Answer>,
+    ) -> Self {
+        Self::with_solutions(vec![
+            Some(solution_1.into()),
+            Some(solution_2.into()),
+            Some(solution_3.into()),
+        ])
+    }
+
+    /// Create a tester with solutions of selected parts only. Number of parts is given by the
+    /// solver. Testing of a part without solution fails.
+    ///
+    /// # Arguments
+    ///
+    /// _solutions_ - Pairs of the part number and its expected answer
+    pub fn new_with_solutions(solutions: Vec<(usize, Answer)>) -> Self {
+        let parts = T::new().get_parts_count();
+
+        Self::with_solutions(
+            (1..=parts)
+                .map(|part| {
+                    solutions
+                        .iter()
+                        .find(|(solution_part, _)| *solution_part == part)
+                        .map(|(_, solution)| solution.clone())
+                })
+                .collect(),
+        )
+    }
+
+    /// Create a tester with solutions loaded from the known answers file _answers.txt_ instead
+    /// of constants, see [PuzzleAnswers]. Number of parts is given by the solver. Testing of a
+    /// part without known answer fails.
+    pub fn new_from_answers() -> Self {
+        let answers = PuzzleAnswers::load_from_resources()
+            .unwrap_or_else(|err| panic!("Failed to load answers with error '{}'", err))
+            .expect("Missing answers file");
+
+        let parts = T::new().get_parts_count();
+
+        Self::with_solutions(
+            (1..=parts)
+                .map(|part| answers.get(part).map(Answer::from))
+                .collect(),
+        )
+    }
+
+    fn with_solutions(solutions: Vec<Option<Answer>>) -> Self {
+        let parts = solutions.len();
+        let project = Project::new();
+        let part_files = (1..=parts)
+            .map(|part| project.resource_file(&format!("input_{}.txt", part)))
+            .collect::<Vec<_>>();
+
+        match T::new().parts_share_state() {
+            true => {
+                let (solver, shared_results) = Self::solve_shared_state(&project, &part_files);
+
+                Self {
+                    solvers: vec![solver],
+                    shared_results,
+                    solutions,
+                }
+            }
+            false => Self {
+                solvers: Self::create_solvers(&project, &part_files),
+                shared_results: Vec::new(),
+                solutions,
+            },
         }
     }
 
-    /// Create a solver instance which can be used to validate parts algorithms.
+    /// Create solver instances which can be used to validate parts algorithms.
+    ///
+    /// # Arguments
+    ///
+    /// _project_ - Project with the input files
+    /// _part_files_ - Input files of the parts, they do not need to exist
     ///
     /// # Returns
     ///
-    /// Mutex to solver because unit tests are executed in a different threads
-    fn create_solver() -> Mutex<T> {
-        // Read input file
-        let input_file = Project::new().resource_file("input.txt");
-        let file = File::open(&input_file)
+    /// Single solver shared by all parts if there are no input files per part, otherwise one
+    /// solver for every part.
+    fn create_solvers(project: &Project, part_files: &[PathBuf]) -> Vec<T> {
+        // All parts share the same input file
+        if part_files.iter().all(|file| !file.exists()) {
+            let input_file = project.resource_file("input.txt");
+            let lines = Self::read_lines(&input_file);
+            let lines: Vec<&str> = lines.iter().map(|s| s.as_str()).collect();
+
+            let mut solver = T::new();
+            solver
+                .parse_input_file(&lines)
+                .map_err(|err| err.with_file(&input_file.to_string_lossy()))
+                .unwrap_or_else(|err| panic!("Failed to parse input file with error '{}'", err));
+
+            return vec![solver];
+        }
+
+        // Every part has its own solver, parts without input file fall back to the shared one
+        part_files
+            .iter()
+            .enumerate()
+            .map(|(i, part_file)| {
+                let part = i + 1;
+                let mut solver = T::new();
+
+                let input_file = match part_file.exists() {
+                    true => part_file.clone(),
+                    false => project.resource_file("input.txt"),
+                };
+                let lines = Self::read_lines(&input_file);
+                let lines: Vec<&str> = lines.iter().map(|s| s.as_str()).collect();
+
+                let result = match part_file.exists() {
+                    true => solver.parse_input_part(part, &lines),
+                    false => solver.parse_input_file(&lines),
+                };
+
+                result
+                    .map_err(|err| err.with_file(&input_file.to_string_lossy()))
+                    .unwrap_or_else(|err| {
+                        panic!(
+                            "Failed to parse input file of part {} with error '{}'",
+                            part, err
+                        )
+                    });
+
+                solver
+            })
+            .collect()
+    }
+
+    /// Solve all parts of the puzzle whose parts share state in order by a single solver. Input
+    /// file _input.txt_ is parsed once before the first part, input file _input_N.txt_ of the
+    /// part is parsed right before the part is solved.
+    ///
+    /// # Arguments
+    ///
+    /// _project_ - Project with the input files
+    /// _part_files_ - Input files of the parts, they do not need to exist
+    ///
+    /// # Returns
+    ///
+    /// Solver with the state after the last part and results of all parts, panic of a part is
+    /// recorded as [PuzzleError::PanicError](super::puzzle_error::PuzzleError::PanicError)
+    fn solve_shared_state(project: &Project, part_files: &[PathBuf]) -> (T, Vec<SolutionResult>) {
+        let shared_file = project.resource_file("input.txt");
+        let has_part_files = part_files.iter().any(|file| file.exists());
+        let mut solver = T::new();
+        let mut results = Vec::new();
+
+        if !has_part_files {
+            Self::parse_file(&mut solver, &shared_file, None);
+        }
+
+        for (i, part_file) in part_files.iter().enumerate() {
+            let part = i + 1;
+
+            if has_part_files {
+                match part_file.exists() {
+                    true => Self::parse_file(&mut solver, part_file, Some(part)),
+                    false => Self::parse_file(&mut solver, &shared_file, None),
+                }
+            }
+
+            results.push(catch_panic(|| solver.solve_part_mut(part)));
+        }
+
+        (solver, results)
+    }
+
+    fn parse_file(solver: &mut T, input_file: &Path, part: Option<usize>) {
+        let lines = Self::read_lines(input_file);
+        let lines: Vec<&str> = lines.iter().map(|s| s.as_str()).collect();
+
+        let result = match part {
+            Some(part) => solver.parse_input_part(part, &lines),
+            None => solver.parse_input_file(&lines),
+        };
+
+        result
+            .map_err(|err| err.with_file(&input_file.to_string_lossy()))
+            .unwrap_or_else(|err| {
+                panic!(
+                    "Failed to parse input file '{}' with error '{}'",
+                    input_file.display(),
+                    err
+                )
+            });
+    }
+
+    fn read_lines(input_file: &Path) -> Vec<String> {
+        let file = File::open(input_file)
             .unwrap_or_else(|err| panic!("Failed to open file with an error '{}'", err));
         let reader = BufReader::new(file);
-        let lines = reader
+
+        reader
             .lines()
             .collect::<Result<Vec<String>, _>>()
-            .unwrap_or_else(|err| panic!("Failed to unwrap lines with an error '{}'", err));
+            .unwrap_or_else(|err| panic!("Failed to unwrap lines with an error '{}'", err))
+    }
 
-        let lines: Vec<&str> = lines.iter().map(|s| s.as_str()).collect();
+    pub fn test_part_1(&self) {
+        self.test_part(1);
+    }
 
-        // Prepare solver
-        let mut solver = T::new();
+    pub fn test_part_2(&self) {
+        self.test_part(2);
+    }
 
-        // Parse input file
-        solver
-            .parse_input_file(&lines)
-            .unwrap_or_else(|err| panic!("Failed to parse input file with error '{}'", err));
+    pub fn test_part_3(&self) {
+        self.test_part(3);
+    }
 
-        Mutex::new(solver)
+    /// Get the parsed solver of the part, e.g. to test helper methods of the solver or to clone
+    /// it for a test which needs its own instance
+    ///
+    /// # Arguments
+    ///
+    /// _part_ - Number of the part starting from 1
+    ///
+    /// # Returns
+    ///
+    /// Solver shared by all parts or the solver of the part if every part has its own input file,
+    /// solver of parts sharing state is returned with the state after the last part
+    pub fn get_solver(&self, part: usize) -> &T {
+        // Parts either share a single solver or every part has its own one
+        match self.solvers.len() {
+            1 => &self.solvers[0],
+            _ => &self.solvers[part - 1],
+        }
     }
 
-    pub fn test_part_1(&self) {
-        let result;
+    fn test_part(&self, part: usize) {
+        let expected = self
+            .solutions
+            .get(part - 1)
+            .and_then(|solution| solution.as_ref())
+            .unwrap_or_else(|| panic!("Missing solution for part {}", part));
 
-        // Solve the puzzle inside a scope so that guard is released automatically avoiding a panic in the thread.
-        {
-            let locked_solver = self.solver.lock().expect("Failed to unwrap 'Solver Mutex'");
-            result = locked_solver.part_1();
-        }
+        // Parts sharing state were already solved when the tester was created
+        let solved;
+        let result = match self.shared_results.get(part - 1) {
+            Some(result) => result,
+            None => {
+                solved = self.get_solver(part).solve_part(part);
+                &solved
+            }
+        };
 
         assert!(result.is_ok(), "Result: {:?}", result);
-        assert_eq!(result.unwrap(), self.solution_1);
+
+        if let Ok(answer) = result {
+            Self::assert_answer(&format!("Part {}", part), answer, expected);
+        }
     }
 
-    pub fn test_part_2(&self) {
-        let result;
+    /// Test all parts of the example which have an expected answer. Every part is solved by a
+    /// fresh solver whose input is parsed by [PuzzleSolver::parse_input_part].
+    ///
+    /// # Arguments
+    ///
+    /// _example_ - Example from the puzzle text with expected answers
+    pub fn test_example(example: &PuzzleExample) {
+        let mut solutions = example.solutions().peekable();
+
+        assert!(
+            solutions.peek().is_some(),
+            "Example '{}' has no expected answer",
+            example.get_name()
+        );
 
-        // Solve the puzzle inside a scope so that guard is released automatically avoiding a panic in the thread.
-        {
-            let locked_solver = self.solver.lock().expect("Failed to unwrap 'Solver Mutex'");
-            result = locked_solver.part_2();
+        for (part, expected) in solutions {
+            Self::test_example_part(example, part, expected);
         }
+    }
 
-        assert!(result.is_ok(), "Result: {:?}", result);
-        assert_eq!(result.unwrap(), self.solution_2);
+    /// Test all examples, see [PuzzleTester::test_example]
+    pub fn test_examples(examples: &[PuzzleExample]) {
+        for example in examples {
+            Self::test_example(example);
+        }
+    }
+
+    fn test_example_part(example: &PuzzleExample, part: usize, expected: &Answer) {
+        let lines = match example.input() {
+            ExampleInput::Inline(input) => input.lines().map(String::from).collect(),
+            ExampleInput::File(path) => Self::read_lines(path),
+        };
+        let lines: Vec<&str> = lines.iter().map(|s| s.as_str()).collect();
+
+        let mut solver = T::new();
+        let result = solver
+            .parse_input_part(part, &lines)
+            .map_err(|err| err.with_file(example.get_name()));
+
+        result.unwrap_or_else(|err| {
+            panic!(
+                "Failed to parse example '{}' of part {} with error '{}'",
+                example.get_name(),
+                part,
+                err
+            )
+        });
+
+        let result = Self::solve_owned(&mut solver, part);
+
+        assert!(
+            result.is_ok(),
+            "Example '{}', part {}, result: {:?}",
+            example.get_name(),
+            part,
+            result
+        );
+        Self::assert_answer(
+            &format!("Example '{}', part {}", example.get_name(), part),
+            &result.unwrap(),
+            expected,
+        );
+    }
+
+    fn solve_owned(solver: &mut T, part: usize) -> SolutionResult {
+        // Parts sharing state continue from the state left by the previous parts
+        if !solver.parts_share_state() {
+            return solver.solve_part(part);
+        }
+
+        for previous in 1..part {
+            solver.solve_part_mut(previous)?;
+        }
+
+        solver.solve_part_mut(part)
+    }
+
+    fn assert_answer(context: &str, answer: &Answer, expected: &Answer) {
+        assert!(
+            answer == expected,
+            "{} answer mismatch\n   answer: {} ({})\n expected: {} ({})",
+            context,
+            answer,
+            answer.kind(),
+            expected,
+            expected.kind()
+        );
     }
 }
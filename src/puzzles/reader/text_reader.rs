@@ -2,6 +2,7 @@ use std::{
     fs::File,
     io::{BufRead, BufReader},
     path::{Path, PathBuf},
+    sync::OnceLock,
 };
 
 use crate::puzzles::puzzle_error::PuzzleError;
@@ -9,9 +10,16 @@ use crate::puzzles::puzzle_error::PuzzleError;
 /// Preallocated capacity of vector where lines are stored
 const LINES_CAPACITY: usize = 1024;
 
+/// Name used instead of the file path when lines are read from the standard input
+const STDIN_NAME: &str = "<stdin>";
+
 /// Reads input text file
 pub struct TextReader {
     path: PathBuf,
+
+    /// Lines read from the standard input, standard input can be read only once so the lines
+    /// are cached for subsequent reads. `None` for regular files.
+    stdin_lines: Option<OnceLock<Vec<String>>>,
 }
 
 impl TextReader {
@@ -28,6 +36,20 @@ impl TextReader {
     pub fn new(path: &Path) -> Self {
         Self {
             path: path.to_path_buf(),
+            stdin_lines: None,
+        }
+    }
+
+    /// Creates a new instance of text reader which reads lines from the standard input
+    ///
+    /// # Returns
+    ///
+    /// New instance of TextReader
+    ///
+    pub fn new_stdin() -> Self {
+        Self {
+            path: PathBuf::from(STDIN_NAME),
+            stdin_lines: Some(OnceLock::new()),
         }
     }
 
@@ -42,14 +64,30 @@ impl TextReader {
     }
 
     pub fn read_lines(&self) -> Result<Vec<String>, PuzzleError> {
+        if let Some(stdin_lines) = &self.stdin_lines {
+            if let Some(lines) = stdin_lines.get() {
+                return Ok(lines.clone());
+            }
+
+            let lines = self.read_lines_from(std::io::stdin().lock())?;
+            return Ok(stdin_lines.get_or_init(|| lines).clone());
+        }
+
         // Open file for text reading
         let file = File::open(&self.path).map_err(|err| {
             PuzzleError::OpenFileError(self.path.to_string_lossy().to_string(), err)
         })?;
 
         // Create buffered reader for efficient reading
-        let reader = BufReader::new(file);
+        self.read_lines_from(BufReader::new(file))
+    }
 
+    /// Read all lines from the buffered reader, path of this reader is used only for error
+    /// reporting
+    pub fn read_lines_from<R>(&self, reader: R) -> Result<Vec<String>, PuzzleError>
+    where
+        R: BufRead,
+    {
         let mut lines = Vec::with_capacity(LINES_CAPACITY);
 
         // Read all lines from the file
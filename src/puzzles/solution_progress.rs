@@ -0,0 +1,123 @@
+use std::{cell::RefCell, sync::Arc};
+
+use super::solution_progress_bar::SolutionProgressBar;
+
+thread_local! {
+    /// Progress bar of the part which is being solved by the current thread
+    static CURRENT: RefCell<Option<Arc<SolutionProgressBar>>> = const { RefCell::new(None) };
+}
+
+/// Handle which lets the solver report progress of the part being solved. Once the total is set
+/// the spinner switches to a bar with percentage, rate and ETA. The handle does nothing if there
+/// is no progress bar, e.g. in tests or in quiet mode.
+///
+/// ```
+/// use advent_of_code::puzzles::solution_progress::SolutionProgress;
+///
+/// let progress = SolutionProgress::current();
+/// progress.set_total(1000);
+///
+/// for _ in 0..1000 {
+///     progress.inc(1);
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct SolutionProgress {
+    progress_bar: Option<Arc<SolutionProgressBar>>,
+}
+
+impl SolutionProgress {
+    /// Get progress handle of the part being solved by the current thread. Handle can be cloned
+    /// and passed to other threads.
+    pub fn current() -> Self {
+        Self {
+            progress_bar: CURRENT.with(|current| current.borrow().clone()),
+        }
+    }
+
+    /// Check if the progress is displayed
+    pub fn is_active(&self) -> bool {
+        self.progress_bar.is_some()
+    }
+
+    /// Set total number of steps of the part, progress is reset to zero
+    pub fn set_total(&self, total: u64) {
+        if let Some(progress_bar) = &self.progress_bar {
+            progress_bar.set_total(total);
+        }
+    }
+
+    /// Advance progress by the given number of steps
+    pub fn inc(&self, steps: u64) {
+        if let Some(progress_bar) = &self.progress_bar {
+            progress_bar.inc(steps);
+        }
+    }
+
+    /// Set number of finished steps
+    pub fn set_position(&self, position: u64) {
+        if let Some(progress_bar) = &self.progress_bar {
+            progress_bar.set_position(position);
+        }
+    }
+
+    /// Make the progress bar current for the calling thread until the returned guard is dropped
+    pub(crate) fn enter(progress_bar: Arc<SolutionProgressBar>) -> SolutionProgressGuard {
+        let previous = CURRENT.with(|current| current.borrow_mut().replace(progress_bar));
+
+        SolutionProgressGuard { previous }
+    }
+}
+
+/// Restores previous progress bar of the thread on destruction
+pub(crate) struct SolutionProgressGuard {
+    previous: Option<Arc<SolutionProgressBar>>,
+}
+
+impl Drop for SolutionProgressGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| *current.borrow_mut() = self.previous.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_without_bar() {
+        let progress = SolutionProgress::current();
+
+        assert!(!progress.is_active());
+
+        // Nothing happens without progress bar
+        progress.set_total(10);
+        progress.inc(1);
+        progress.set_position(5);
+    }
+
+    #[test]
+    fn test_progress_current() {
+        let progress_bar = Arc::new(SolutionProgressBar::new_hidden());
+
+        {
+            let _guard = SolutionProgress::enter(progress_bar.clone());
+            let progress = SolutionProgress::current();
+
+            assert!(progress.is_active());
+
+            progress.set_total(10);
+            progress.inc(3);
+            assert_eq!(progress_bar.position(), 3);
+
+            progress.set_position(7);
+            assert_eq!(progress_bar.position(), 7);
+
+            // Ticks of the spinner do not move the bar once the total is known
+            progress_bar.tick();
+            assert_eq!(progress_bar.position(), 7);
+        }
+
+        assert!(!SolutionProgress::current().is_active());
+    }
+}
@@ -1,8 +1,15 @@
-use indicatif::{ProgressBar, ProgressStyle};
+use std::sync::{Mutex, PoisonError};
+
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
 
 /// Command line progress bar to show progress of the computation in case of longer tasks.
 pub struct SolutionProgressBar {
     progress_bar: ProgressBar,
+
+    /// Total number of steps is known, i.e. progress bar is shown instead of a spinner. Lock is
+    /// held while the mode is switched or the progress bar ticks, so a tick never applies to
+    /// the wrong mode.
+    has_total: Mutex<bool>,
 }
 
 impl SolutionProgressBar {
@@ -16,6 +23,52 @@ impl SolutionProgressBar {
     ///
     /// New instance of Progress
     pub fn new(prefix: &str) -> Self {
+        let progress_bar = Self::create_progress_bar(prefix);
+
+        // Show first progress bar status
+        progress_bar.tick();
+
+        Self {
+            progress_bar,
+            has_total: Mutex::new(false),
+        }
+    }
+
+    /// Create new instance of the progress bar displayed together with other progress bars of
+    /// the multi progress
+    ///
+    /// # Arguments
+    ///
+    /// _multi_progress_ - Multi progress the progress bar is added to
+    /// _prefix_ - Progress bar prefix
+    ///
+    /// # Returns
+    ///
+    /// New instance of Progress
+    pub fn new_in(multi_progress: &MultiProgress, prefix: &str) -> Self {
+        let progress_bar = multi_progress.add(Self::create_progress_bar(prefix));
+
+        // Show first progress bar status
+        progress_bar.tick();
+
+        Self {
+            progress_bar,
+            has_total: Mutex::new(false),
+        }
+    }
+
+    /// Create new instance of the progress bar which is never drawn
+    pub fn new_hidden() -> Self {
+        let progress_bar = Self::create_progress_bar("");
+        progress_bar.set_draw_target(ProgressDrawTarget::hidden());
+
+        Self {
+            progress_bar,
+            has_total: Mutex::new(false),
+        }
+    }
+
+    fn create_progress_bar(prefix: &str) -> ProgressBar {
         let progress_style = ProgressStyle::default_spinner()
             .template("{prefix} {spinner}")
             .expect("Failed to create ProgressStyle")
@@ -34,19 +87,55 @@ impl SolutionProgressBar {
                 "",
             ]);
 
-        let progress_bar = ProgressBar::new_spinner()
+        ProgressBar::new_spinner()
             .with_style(progress_style)
-            .with_prefix(prefix.to_string());
+            .with_prefix(prefix.to_string())
+    }
 
-        // Show first progress bar status
-        progress_bar.tick();
+    /// Update progress bar with next tick, spinner moves while the bar is only redrawn
+    pub fn tick(&self) {
+        let has_total = self
+            .has_total
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
 
-        Self { progress_bar }
+        match *has_total {
+            true => self.progress_bar.tick(),
+            false => self.progress_bar.inc(1),
+        }
     }
 
-    /// Update progress bar with next tick
-    pub fn tick(&self) {
-        self.progress_bar.inc(1);
+    /// Set total number of steps and switch the spinner to a bar with percentage, rate and ETA
+    pub fn set_total(&self, total: u64) {
+        let progress_style = ProgressStyle::default_bar()
+            .template("{prefix} [{bar:40}] {percent:>3}% {per_sec} ETA {eta}")
+            .expect("Failed to create ProgressStyle")
+            .progress_chars("=> ");
+
+        let mut has_total = self
+            .has_total
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        *has_total = true;
+        self.progress_bar.set_style(progress_style);
+        self.progress_bar.set_length(total);
+        self.progress_bar.reset();
+    }
+
+    /// Advance progress bar by the given number of steps
+    pub fn inc(&self, steps: u64) {
+        self.progress_bar.inc(steps);
+    }
+
+    /// Set number of finished steps
+    pub fn set_position(&self, position: u64) {
+        self.progress_bar.set_position(position);
+    }
+
+    /// Get number of finished steps
+    pub fn position(&self) -> u64 {
+        self.progress_bar.position()
     }
 
     /// Finish progress bar
@@ -54,3 +143,41 @@ impl SolutionProgressBar {
         self.progress_bar.finish_and_clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_with_total() {
+        let progress_bar = SolutionProgressBar::new_hidden();
+
+        // Spinner moves with every tick
+        progress_bar.tick();
+        progress_bar.tick();
+        assert_eq!(progress_bar.position(), 2);
+
+        // Progress bar is reset and ticks only redraw it
+        progress_bar.set_total(10);
+        progress_bar.tick();
+        assert_eq!(progress_bar.position(), 0);
+    }
+
+    #[test]
+    fn test_tick_concurrent_with_set_total() {
+        let progress_bar = SolutionProgressBar::new_hidden();
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                for _ in 0..10_000 {
+                    progress_bar.tick();
+                }
+            });
+
+            progress_bar.set_total(100);
+        });
+
+        // Ticks after the switch to the bar never move it
+        assert_eq!(progress_bar.position(), 0);
+    }
+}
@@ -7,6 +7,8 @@ use std::{
     time::Duration,
 };
 
+use indicatif::MultiProgress;
+
 use super::solution_progress_bar::SolutionProgressBar;
 
 /// Command line progress bar spawned and updated in a separate thread
@@ -26,6 +28,20 @@ impl SolutionProgressBarThread {
         }
     }
 
+    /// Creates new instance displayed together with other progress bars of the multi progress
+    pub fn new_in(multi_progress: &MultiProgress, prefix: &str) -> Self {
+        Self {
+            progress: Arc::new(SolutionProgressBar::new_in(multi_progress, prefix)),
+            thread_handle: None,
+            tx_stop_signal: None,
+        }
+    }
+
+    /// Get the progress bar updated by the thread
+    pub fn progress(&self) -> Arc<SolutionProgressBar> {
+        self.progress.clone()
+    }
+
     /// Starts the execution of the progress bar in a separate thread until terminated
     pub fn run(&mut self) {
         // Create channel to stop of the thread
@@ -54,15 +70,17 @@ impl SolutionProgressBarThread {
         self.thread_handle = Some(handle);
     }
 
-    /// Stops the execution of the progress bar and finalize output
+    /// Stops the execution of the progress bar and finalize output, the bar is cleared even if
+    /// the thread is unwinding
     fn finish(&mut self) {
-        if let Some(ref tx) = self.tx_stop_signal {
-            if let Err(err) = tx.send(()) {
-                panic!(
-                    "Failed to send signal to stop ProgressBar with error '{}'",
-                    err
-                );
-            }
+        if let Some(ref tx) = self.tx_stop_signal
+            && let Err(err) = tx.send(())
+            && !std::thread::panicking()
+        {
+            panic!(
+                "Failed to send signal to stop ProgressBar with error '{}'",
+                err
+            );
         }
 
         self.progress.finish();
@@ -0,0 +1,307 @@
+use std::marker::PhantomData;
+
+use super::{
+    answer::Answer,
+    puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
+    puzzle_solver::PuzzleSolver,
+};
+
+/// Alternative to [PuzzleSolver] where parsing returns the parsed input instead of storing it
+/// into the solver, so a part cannot be solved before the input is parsed. Use [TypedSolver] to
+/// solve it by [Puzzle](super::puzzle::Puzzle) or to test it by
+/// [PuzzleTester](super::puzzle_tester::PuzzleTester), e.g. `Puzzle::<TypedSolver<Solver>>`.
+pub trait TypedPuzzleSolver {
+    /// Parsed input of the puzzle
+    type Input;
+
+    /// Get puzzle description
+    ///
+    /// # Returns
+    ///
+    /// String representation of puzzle description
+    fn get_description() -> &'static str;
+
+    /// Get number of parts of the puzzle, Advent of Code puzzles have two parts and Everybody
+    /// Codes quests have three parts
+    ///
+    /// # Returns
+    ///
+    /// Number of parts of the puzzle
+    fn get_parts_count() -> usize {
+        2
+    }
+
+    /// Parse content of the input file
+    ///
+    /// # Arguments
+    ///
+    /// _lines_ - Lines read from input file
+    ///
+    /// # Returns
+    ///
+    /// Parsed input on success, error on failure
+    fn parse_input(lines: &[&str]) -> Result<Self::Input, PuzzleError>;
+
+    /// Parse content of the input file of the given part, used if every part of the puzzle has
+    /// its own input file. Default implementation delegates to [TypedPuzzleSolver::parse_input].
+    ///
+    /// # Arguments
+    ///
+    /// _part_ - Number of the part starting from 1
+    /// _lines_ - Lines read from input file of the part
+    ///
+    /// # Returns
+    ///
+    /// Parsed input on success, error on failure
+    fn parse_input_part(_part: usize, lines: &[&str]) -> Result<Self::Input, PuzzleError> {
+        Self::parse_input(lines)
+    }
+
+    /// Solve part 1 of the puzzle
+    ///
+    /// # Arguments
+    ///
+    /// _input_ - Parsed input of the puzzle
+    ///
+    /// # Returns
+    ///
+    /// Answer of the part on success, error on failure
+    fn part_1(_input: &Self::Input) -> SolutionResult {
+        Ok(Answer::from("Not solved"))
+    }
+
+    /// Solve part 2 of the puzzle
+    ///
+    /// # Arguments
+    ///
+    /// _input_ - Parsed input of the puzzle
+    ///
+    /// # Returns
+    ///
+    /// Answer of the part on success, error on failure
+    fn part_2(_input: &Self::Input) -> SolutionResult {
+        Ok(Answer::from("Not solved"))
+    }
+
+    /// Solve part 3 of the puzzle, used only if [TypedPuzzleSolver::get_parts_count] returns 3
+    ///
+    /// # Arguments
+    ///
+    /// _input_ - Parsed input of the puzzle
+    ///
+    /// # Returns
+    ///
+    /// Answer of the part on success, error on failure
+    fn part_3(_input: &Self::Input) -> SolutionResult {
+        Ok(Answer::from("Not solved"))
+    }
+
+    /// Check if the parts share state, see [PuzzleSolver::parts_share_state]
+    ///
+    /// # Returns
+    ///
+    /// `true` if the parts share state, `false` by default
+    fn parts_share_state() -> bool {
+        false
+    }
+
+    /// Solve part 1 of the puzzle with mutable access to the input, default implementation
+    /// delegates to [TypedPuzzleSolver::part_1]
+    ///
+    /// # Arguments
+    ///
+    /// _input_ - Parsed input of the puzzle, changes are visible to the next parts
+    ///
+    /// # Returns
+    ///
+    /// Answer of the part on success, error on failure
+    fn part_1_mut(input: &mut Self::Input) -> SolutionResult {
+        Self::part_1(input)
+    }
+
+    /// Solve part 2 of the puzzle with mutable access to the input, default implementation
+    /// delegates to [TypedPuzzleSolver::part_2]
+    ///
+    /// # Arguments
+    ///
+    /// _input_ - Parsed input of the puzzle, changes are visible to the next parts
+    ///
+    /// # Returns
+    ///
+    /// Answer of the part on success, error on failure
+    fn part_2_mut(input: &mut Self::Input) -> SolutionResult {
+        Self::part_2(input)
+    }
+
+    /// Solve part 3 of the puzzle with mutable access to the input, default implementation
+    /// delegates to [TypedPuzzleSolver::part_3]
+    ///
+    /// # Arguments
+    ///
+    /// _input_ - Parsed input of the puzzle, changes are visible to the next parts
+    ///
+    /// # Returns
+    ///
+    /// Answer of the part on success, error on failure
+    fn part_3_mut(input: &mut Self::Input) -> SolutionResult {
+        Self::part_3(input)
+    }
+}
+
+/// Adapts [TypedPuzzleSolver] to [PuzzleSolver], the adapter holds the parsed input
+pub struct TypedSolver<S>
+where
+    S: TypedPuzzleSolver,
+{
+    input: Option<S::Input>,
+    solver: PhantomData<fn() -> S>,
+}
+
+impl<S> TypedSolver<S>
+where
+    S: TypedPuzzleSolver,
+{
+    /// Get parsed input, `None` if the input is not parsed yet
+    pub fn get_input(&self) -> Option<&S::Input> {
+        self.input.as_ref()
+    }
+
+    fn input(&self) -> Result<&S::Input, PuzzleError> {
+        self.input.as_ref().ok_or_else(Self::not_parsed)
+    }
+
+    fn input_mut(&mut self) -> Result<&mut S::Input, PuzzleError> {
+        self.input.as_mut().ok_or_else(Self::not_parsed)
+    }
+
+    fn not_parsed() -> PuzzleError {
+        PuzzleError::GenericError(String::from("Puzzle input has not been parsed"))
+    }
+}
+
+impl<S> PuzzleSolver for TypedSolver<S>
+where
+    S: TypedPuzzleSolver,
+{
+    fn new() -> Self {
+        Self {
+            input: None,
+            solver: PhantomData,
+        }
+    }
+
+    fn get_description(&self) -> &str {
+        S::get_description()
+    }
+
+    fn get_parts_count(&self) -> usize {
+        S::get_parts_count()
+    }
+
+    fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
+        self.input = Some(S::parse_input(lines)?);
+        Ok(())
+    }
+
+    fn parse_input_part(&mut self, part: usize, lines: &[&str]) -> PuzzleResult {
+        self.input = Some(S::parse_input_part(part, lines)?);
+        Ok(())
+    }
+
+    fn part_1(&self) -> SolutionResult {
+        S::part_1(self.input()?)
+    }
+
+    fn part_2(&self) -> SolutionResult {
+        S::part_2(self.input()?)
+    }
+
+    fn part_3(&self) -> SolutionResult {
+        S::part_3(self.input()?)
+    }
+
+    fn parts_share_state(&self) -> bool {
+        S::parts_share_state()
+    }
+
+    fn part_1_mut(&mut self) -> SolutionResult {
+        S::part_1_mut(self.input_mut()?)
+    }
+
+    fn part_2_mut(&mut self) -> SolutionResult {
+        S::part_2_mut(self.input_mut()?)
+    }
+
+    fn part_3_mut(&mut self) -> SolutionResult {
+        S::part_3_mut(self.input_mut()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct SumSolver {}
+
+    impl TypedPuzzleSolver for SumSolver {
+        type Input = Vec<i64>;
+
+        fn get_description() -> &'static str {
+            "--- Sum ---"
+        }
+
+        fn parse_input(lines: &[&str]) -> Result<Self::Input, PuzzleError> {
+            lines.iter().map(|line| Ok(line.parse::<i64>()?)).collect()
+        }
+
+        fn part_1(input: &Self::Input) -> SolutionResult {
+            Ok(input.iter().sum::<i64>().into())
+        }
+    }
+
+    #[test]
+    fn test_typed_solver() {
+        let mut solver = TypedSolver::<SumSolver>::new();
+
+        assert_eq!(solver.get_description(), "--- Sum ---");
+        assert_eq!(solver.get_parts_count(), 2);
+
+        let result = solver.parse_input_file(&["1", "-5", "2"]);
+        assert!(result.is_ok(), "result: {:?}", result);
+        assert_eq!(solver.get_input(), Some(&vec![1, -5, 2]));
+
+        let result = solver.solve_part(1);
+        assert!(result.is_ok(), "result: {:?}", result);
+        assert_eq!(result.unwrap(), Answer::from(-2_i64));
+
+        let result = solver.solve_part(2);
+        assert!(result.is_ok(), "result: {:?}", result);
+        assert_eq!(result.unwrap(), Answer::from("Not solved"));
+    }
+
+    #[test]
+    fn test_typed_solver_not_parsed() {
+        let solver = TypedSolver::<SumSolver>::new();
+        let result = solver.solve_part(1);
+
+        assert!(
+            matches!(result, Err(PuzzleError::GenericError(_))),
+            "result: {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_typed_solver_invalid_input() {
+        let mut solver = TypedSolver::<SumSolver>::new();
+        let result = solver.parse_input_file(&["1", "x"]);
+
+        assert!(
+            matches!(result, Err(PuzzleError::InvalidContentError(_))),
+            "result: {:?}",
+            result
+        );
+        assert!(solver.get_input().is_none());
+    }
+}
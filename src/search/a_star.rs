@@ -0,0 +1,113 @@
+use std::{collections::BinaryHeap, hash::Hash};
+
+use super::{heap_entry::HeapEntry, search_result::SearchResult};
+
+/// A* search over a graph with non-negative edge costs guided by a heuristic
+pub struct AStar;
+
+impl AStar {
+    /// Find one of the cheapest paths from the start to the goal.
+    ///
+    /// # Arguments
+    ///
+    /// _start_ - Start node
+    /// _successors_ - Closure returning all nodes reachable from the given node together with
+    /// the cost of the step
+    /// _heuristic_ - Estimated cost from the node to the goal, it must never overestimate the
+    /// real cost otherwise the found path does not have to be the cheapest one
+    /// _is_goal_ - Closure identifying goal nodes
+    ///
+    /// # Returns
+    ///
+    /// Path from the start to the goal (both included) and its cost, `None` if goal is not
+    /// reachable
+    pub fn shortest_path<N, F, I, H, G>(
+        start: N,
+        mut successors: F,
+        heuristic: H,
+        is_goal: G,
+    ) -> Option<(Vec<N>, usize)>
+    where
+        N: Clone + Eq + Hash,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = (N, usize)>,
+        H: Fn(&N) -> usize,
+        G: Fn(&N) -> bool,
+    {
+        let mut result = SearchResult::default();
+        result.insert_start(start.clone());
+
+        let mut heap = BinaryHeap::from([HeapEntry {
+            priority: heuristic(&start),
+            cost: 0,
+            node: start,
+        }]);
+
+        while let Some(HeapEntry { cost, node, .. }) = heap.pop() {
+            // Skip outdated entries, node has been reached cheaper in the meantime
+            if result.distance(&node).is_some_and(|known| cost > known) {
+                continue;
+            }
+
+            if is_goal(&node) {
+                return Some((result.path(&node)?, cost));
+            }
+
+            for (next, step) in successors(&node) {
+                let next_cost = cost + step;
+
+                if result.relax(&node, &next, next_cost) {
+                    heap.push(HeapEntry {
+                        priority: next_cost + heuristic(&next),
+                        cost: next_cost,
+                        node: next,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Walk on the number line, step by one costs 1, jump by five costs 3
+    fn successors(node: &i32) -> Vec<(i32, usize)> {
+        vec![(node + 1, 1), (node - 1, 1), (node + 5, 3), (node - 5, 3)]
+    }
+
+    fn heuristic(node: &i32) -> usize {
+        // Every 5 units costs at least 3
+        (17 - node).unsigned_abs() as usize * 3 / 5
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let result = AStar::shortest_path(0, successors, heuristic, |node| *node == 17);
+
+        assert!(result.is_some(), "result: {:?}", result);
+
+        let (path, cost) = result.unwrap();
+        assert_eq!(cost, 11);
+        assert_eq!(path.first(), Some(&0));
+        assert_eq!(path.last(), Some(&17));
+    }
+
+    #[test]
+    fn test_shortest_path_unreachable() {
+        let result = AStar::shortest_path(
+            0,
+            |node: &i32| match *node < 5 {
+                true => vec![(node + 1, 1)],
+                false => vec![],
+            },
+            |_| 0,
+            |node| *node == 17,
+        );
+
+        assert_eq!(result, None);
+    }
+}
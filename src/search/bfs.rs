@@ -0,0 +1,133 @@
+use std::{collections::VecDeque, hash::Hash};
+
+use super::search_result::SearchResult;
+
+/// Breadth first search over a graph where every edge has the same cost
+pub struct Bfs;
+
+impl Bfs {
+    /// Search the whole graph reachable from the start.
+    ///
+    /// # Arguments
+    ///
+    /// _start_ - Start node
+    /// _neighbors_ - Closure returning all nodes reachable from the given node in a single step
+    ///
+    /// # Returns
+    ///
+    /// Distances to all reachable nodes together with predecessors on all shortest paths
+    pub fn search<N, F, I>(start: N, neighbors: F) -> SearchResult<N>
+    where
+        N: Clone + Eq + Hash,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = N>,
+    {
+        Self::run(start, neighbors, |_| false).0
+    }
+
+    /// Find one of the shortest paths from the start to the first node satisfying the goal.
+    ///
+    /// # Arguments
+    ///
+    /// _start_ - Start node
+    /// _neighbors_ - Closure returning all nodes reachable from the given node in a single step
+    /// _is_goal_ - Closure identifying the goal node
+    ///
+    /// # Returns
+    ///
+    /// Path from the start to the goal (both included), `None` if goal is not reachable
+    pub fn shortest_path<N, F, I, G>(start: N, neighbors: F, is_goal: G) -> Option<Vec<N>>
+    where
+        N: Clone + Eq + Hash,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = N>,
+        G: Fn(&N) -> bool,
+    {
+        let (result, goal) = Self::run(start, neighbors, is_goal);
+        goal.and_then(|goal| result.path(&goal))
+    }
+
+    fn run<N, F, I, G>(start: N, mut neighbors: F, is_goal: G) -> (SearchResult<N>, Option<N>)
+    where
+        N: Clone + Eq + Hash,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = N>,
+        G: Fn(&N) -> bool,
+    {
+        let mut result = SearchResult::default();
+        result.insert_start(start.clone());
+
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(node) = queue.pop_front() {
+            if is_goal(&node) {
+                return (result, Some(node));
+            }
+
+            let distance = result
+                .distance(&node)
+                .expect("Queued node has to have a distance")
+                + 1;
+
+            for neighbor in neighbors(&node) {
+                if result.relax(&node, &neighbor, distance) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        (result, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Numbers 0..10, every number is connected to its successor and to its double
+    fn neighbors(node: &u32) -> Vec<u32> {
+        [node + 1, node * 2]
+            .into_iter()
+            .filter(|next| *next < 10)
+            .collect()
+    }
+
+    #[test]
+    fn test_search() {
+        let result = Bfs::search(1, neighbors);
+
+        assert_eq!(result.distance(&1), Some(0));
+        assert_eq!(result.distance(&2), Some(1));
+        assert_eq!(result.distance(&4), Some(2));
+        assert_eq!(result.distance(&8), Some(3));
+        assert_eq!(result.distance(&9), Some(4));
+        assert_eq!(result.distance(&0), None);
+    }
+
+    #[test]
+    fn test_search_all_predecessors() {
+        // Steps by one or by two, e.g. 3 is reached both from 1 and 2
+        let result = Bfs::search(0, |node: &u32| {
+            [node + 1, node + 2]
+                .into_iter()
+                .filter(|next| *next < 10)
+                .collect::<Vec<_>>()
+        });
+
+        let mut predecessors = result.predecessors(&3).to_vec();
+        predecessors.sort();
+        assert_eq!(predecessors, vec![1, 2]);
+        assert_eq!(result.predecessors(&4), &[2]);
+        assert_eq!(result.count_shortest_paths(&3), 2);
+        assert_eq!(result.count_shortest_paths(&5), 3);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let path = Bfs::shortest_path(1, neighbors, |node| *node == 9);
+        assert_eq!(path, Some(vec![1, 2, 4, 8, 9]));
+
+        let path = Bfs::shortest_path(1, neighbors, |node| *node == 42);
+        assert_eq!(path, None);
+    }
+}
@@ -0,0 +1,265 @@
+use std::{collections::BinaryHeap, hash::Hash};
+
+use super::{heap_entry::HeapEntry, search_result::SearchResult};
+
+/// Dijkstra search over a graph with non-negative edge costs
+pub struct Dijkstra;
+
+impl Dijkstra {
+    /// Search the whole graph reachable from the start.
+    ///
+    /// # Arguments
+    ///
+    /// _start_ - Start node
+    /// _successors_ - Closure returning all nodes reachable from the given node together with
+    /// the cost of the step
+    ///
+    /// # Returns
+    ///
+    /// Costs of all reachable nodes together with predecessors on all cheapest paths
+    pub fn search<N, F, I>(start: N, successors: F) -> SearchResult<N>
+    where
+        N: Clone + Eq + Hash,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = (N, usize)>,
+    {
+        Self::run([start], successors, |_| false).0
+    }
+
+    /// Search the graph until the cheapest goal is found. Search continues until all goals with
+    /// the same cost and all their cheapest paths are known, so the result can be used to
+    /// collect nodes on all optimal routes.
+    ///
+    /// # Arguments
+    ///
+    /// _start_ - Start node
+    /// _successors_ - Closure returning all nodes reachable from the given node together with
+    /// the cost of the step
+    /// _is_goal_ - Closure identifying goal nodes
+    ///
+    /// # Returns
+    ///
+    /// Costs of all visited nodes together with predecessors on all cheapest paths
+    pub fn search_until<N, F, I, G>(start: N, successors: F, is_goal: G) -> SearchResult<N>
+    where
+        N: Clone + Eq + Hash,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = (N, usize)>,
+        G: Fn(&N) -> bool,
+    {
+        Self::run([start], successors, is_goal).0
+    }
+
+    /// Same as [Dijkstra::search_until] but the search starts from several nodes at once, e.g.
+    /// the same position with different headings.
+    ///
+    /// # Arguments
+    ///
+    /// _starts_ - Start nodes, all of them have zero cost
+    /// _successors_ - Closure returning all nodes reachable from the given node together with
+    /// the cost of the step
+    /// _is_goal_ - Closure identifying goal nodes
+    ///
+    /// # Returns
+    ///
+    /// Costs of all visited nodes together with predecessors on all cheapest paths
+    pub fn search_many_until<N, S, F, I, G>(starts: S, successors: F, is_goal: G) -> SearchResult<N>
+    where
+        N: Clone + Eq + Hash,
+        S: IntoIterator<Item = N>,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = (N, usize)>,
+        G: Fn(&N) -> bool,
+    {
+        Self::run(starts, successors, is_goal).0
+    }
+
+    /// Find one of the cheapest paths from the start to the goal.
+    ///
+    /// # Arguments
+    ///
+    /// _start_ - Start node
+    /// _successors_ - Closure returning all nodes reachable from the given node together with
+    /// the cost of the step
+    /// _is_goal_ - Closure identifying goal nodes
+    ///
+    /// # Returns
+    ///
+    /// Path from the start to the goal (both included) and its cost, `None` if goal is not
+    /// reachable
+    pub fn shortest_path<N, F, I, G>(start: N, successors: F, is_goal: G) -> Option<(Vec<N>, usize)>
+    where
+        N: Clone + Eq + Hash,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = (N, usize)>,
+        G: Fn(&N) -> bool,
+    {
+        let (result, goal) = Self::run([start], successors, is_goal);
+        let goal = goal?;
+
+        Some((result.path(&goal)?, result.distance(&goal)?))
+    }
+
+    fn run<N, S, F, I, G>(starts: S, mut successors: F, is_goal: G) -> (SearchResult<N>, Option<N>)
+    where
+        N: Clone + Eq + Hash,
+        S: IntoIterator<Item = N>,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = (N, usize)>,
+        G: Fn(&N) -> bool,
+    {
+        let mut result = SearchResult::default();
+        let mut heap = BinaryHeap::new();
+
+        for start in starts {
+            result.insert_start(start.clone());
+            heap.push(HeapEntry {
+                priority: 0,
+                cost: 0,
+                node: start,
+            });
+        }
+        let mut goal = None;
+
+        while let Some(HeapEntry { cost, node, .. }) = heap.pop() {
+            // Everything cheaper than the goal has been processed
+            if goal
+                .as_ref()
+                .is_some_and(|(_, goal_cost)| cost > *goal_cost)
+            {
+                break;
+            }
+
+            // Skip outdated entries, node has been reached cheaper in the meantime
+            if result.distance(&node).is_some_and(|known| cost > known) {
+                continue;
+            }
+
+            if is_goal(&node) {
+                if goal.is_none() {
+                    goal = Some((node, cost));
+                }
+                continue;
+            }
+
+            for (next, step) in successors(&node) {
+                let next_cost = cost + step;
+
+                if result.relax(&node, &next, next_cost) {
+                    heap.push(HeapEntry {
+                        priority: next_cost,
+                        cost: next_cost,
+                        node: next,
+                    });
+                }
+            }
+        }
+
+        (result, goal.map(|(node, _)| node))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    //     1     2
+    //  A --- B --- D
+    //  |           |
+    //  | 1       1 |
+    //  C --------- E --- F
+    //        2       1
+    fn successors(node: &char) -> Vec<(char, usize)> {
+        let edges = [
+            ('A', 'B', 1),
+            ('B', 'D', 2),
+            ('A', 'C', 1),
+            ('C', 'E', 2),
+            ('D', 'E', 1),
+            ('E', 'F', 1),
+        ];
+
+        edges
+            .iter()
+            .filter_map(|(from, to, cost)| match (from == node, to == node) {
+                (true, _) => Some((*to, *cost)),
+                (_, true) => Some((*from, *cost)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_search() {
+        let result = Dijkstra::search('A', successors);
+
+        assert_eq!(result.distance(&'A'), Some(0));
+        assert_eq!(result.distance(&'B'), Some(1));
+        assert_eq!(result.distance(&'C'), Some(1));
+        assert_eq!(result.distance(&'D'), Some(3));
+        assert_eq!(result.distance(&'E'), Some(3));
+        assert_eq!(result.distance(&'F'), Some(4));
+        assert_eq!(result.distance(&'X'), None);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let result = Dijkstra::shortest_path('A', successors, |node| *node == 'D');
+        assert_eq!(result, Some((vec!['A', 'B', 'D'], 3)));
+
+        let result = Dijkstra::shortest_path('A', successors, |node| *node == 'X');
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_search_until_all_paths() {
+        let result = Dijkstra::search_until('A', successors, |node| *node == 'F');
+
+        assert_eq!(result.distance(&'F'), Some(4));
+        assert_eq!(result.count_shortest_paths(&'F'), 1);
+        assert_eq!(result.count_shortest_paths(&'E'), 1);
+        assert_eq!(
+            result.nodes_on_shortest_paths(&['F']),
+            HashSet::from(['A', 'C', 'E', 'F'])
+        );
+    }
+
+    #[test]
+    fn test_search_until_equal_goals() {
+        // Both D and E have the same cost, both have to be reported
+        let result = Dijkstra::search_until('A', successors, |node| *node == 'D' || *node == 'E');
+
+        let mut goals = result.closest(|node| *node == 'D' || *node == 'E');
+        goals.sort();
+        assert_eq!(goals, vec!['D', 'E']);
+    }
+
+    #[test]
+    fn test_search_many_until() {
+        let result = Dijkstra::search_many_until(['D', 'C'], successors, |node| *node == 'F');
+
+        assert_eq!(result.distance(&'D'), Some(0));
+        assert_eq!(result.distance(&'C'), Some(0));
+        assert_eq!(result.distance(&'F'), Some(2));
+        assert_eq!(result.path(&'F'), Some(vec!['D', 'E', 'F']));
+    }
+
+    #[test]
+    fn test_search_zero_cost_cycle() {
+        // Nodes 1 and 2 are connected by zero cost edges in both directions
+        let successors = |node: &u32| match node {
+            0 => vec![(1, 1)],
+            1 => vec![(2, 0)],
+            2 => vec![(1, 0)],
+            _ => vec![],
+        };
+        let result = Dijkstra::search(0, successors);
+
+        assert_eq!(result.distance(&2), Some(1));
+        assert_eq!(result.path(&2), Some(vec![0, 1, 2]));
+        assert_eq!(result.count_shortest_paths(&2), 1);
+        assert_eq!(result.count_shortest_paths(&1), 1);
+    }
+}
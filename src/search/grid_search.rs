@@ -0,0 +1,422 @@
+use crate::grids::{direction::Direction, grid::Grid, point::Point};
+
+use super::{
+    a_star::AStar, bfs::Bfs, dijkstra::Dijkstra, heading::Heading, heading_steps::HeadingSteps,
+    search_result::SearchResult,
+};
+
+/// Searches over the grid moving in [Direction::CARDINAL] directions
+impl<T> Grid<T> {
+    /// Breadth first search from the start point.
+    ///
+    /// # Arguments
+    ///
+    /// _start_ - Start point
+    /// _can_move_ - Closure deciding if it is possible to move from the first point to the
+    /// second (neighboring) point
+    ///
+    /// # Returns
+    ///
+    /// Distances to all reachable points together with predecessors on all shortest paths
+    pub fn bfs<F>(&self, start: &Point, can_move: F) -> SearchResult<Point>
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        Bfs::search(*start, |point| self.cardinal_neighbors(point, &can_move))
+    }
+
+    /// Find one of the shortest paths from the start to the end using breadth first search.
+    ///
+    /// # Arguments
+    ///
+    /// _start_ - Start point
+    /// _end_ - End point
+    /// _can_move_ - Closure deciding if it is possible to move from the first point to the
+    /// second (neighboring) point
+    ///
+    /// # Returns
+    ///
+    /// Path from the start to the end (both included), `None` if end is not reachable
+    pub fn bfs_path<F>(&self, start: &Point, end: &Point, can_move: F) -> Option<Vec<Point>>
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        Bfs::shortest_path(
+            *start,
+            |point| self.cardinal_neighbors(point, &can_move),
+            |point| point == end,
+        )
+    }
+
+    /// Dijkstra search from the start point.
+    ///
+    /// # Arguments
+    ///
+    /// _start_ - Start point
+    /// _cost_ - Closure returning cost of the move from the first point to the second
+    /// (neighboring) point, `None` if the move is not possible
+    ///
+    /// # Returns
+    ///
+    /// Costs of all reachable points together with predecessors on all cheapest paths
+    pub fn dijkstra<F>(&self, start: &Point, cost: F) -> SearchResult<Point>
+    where
+        F: Fn(&Point, &Point) -> Option<usize>,
+    {
+        Dijkstra::search(*start, |point| self.cardinal_successors(point, &cost))
+    }
+
+    /// Find one of the cheapest paths from the start to the end using A* search with Manhattan
+    /// distance as a heuristic. Heuristic is correct only if every move costs at least 1.
+    ///
+    /// # Arguments
+    ///
+    /// _start_ - Start point
+    /// _end_ - End point
+    /// _cost_ - Closure returning cost of the move from the first point to the second
+    /// (neighboring) point, `None` if the move is not possible
+    ///
+    /// # Returns
+    ///
+    /// Path from the start to the end (both included) and its cost, `None` if end is not
+    /// reachable
+    pub fn a_star<F>(&self, start: &Point, end: &Point, cost: F) -> Option<(Vec<Point>, usize)>
+    where
+        F: Fn(&Point, &Point) -> Option<usize>,
+    {
+        AStar::shortest_path(
+            *start,
+            |point| self.cardinal_successors(point, &cost),
+            |point| point.manhattan_distance(end),
+            |point| point == end,
+        )
+    }
+
+    /// Dijkstra search where the state includes the heading and turning has a cost. Search ends
+    /// when all cheapest states at the end point are known.
+    ///
+    /// # Arguments
+    ///
+    /// _start_ - Start point and heading
+    /// _end_ - End point, heading at the end does not matter
+    /// _forward_cost_ - Cost of one step forward
+    /// _turn_cost_ - Cost of turning by 90 degrees
+    /// _can_move_ - Closure deciding if it is possible to move from the first point to the
+    /// second (neighboring) point
+    ///
+    /// # Returns
+    ///
+    /// Costs of all visited states together with predecessors on all cheapest paths
+    pub fn dijkstra_heading<F>(
+        &self,
+        start: &Heading,
+        end: &Point,
+        forward_cost: usize,
+        turn_cost: usize,
+        can_move: F,
+    ) -> SearchResult<Heading>
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        Dijkstra::search_until(
+            *start,
+            |state| self.heading_successors(state, forward_cost, turn_cost, &can_move),
+            |state| state.point == *end,
+        )
+    }
+
+    /// Dijkstra search where the state includes the heading and the number of steps made in a
+    /// straight line. Search starts in all [Direction::CARDINAL] directions and ends when all
+    /// cheapest states at the end point with at least `min_steps` in a straight line are known.
+    ///
+    /// # Arguments
+    ///
+    /// _start_ - Start point
+    /// _end_ - End point
+    /// _min_steps_ - Minimal number of steps in a straight line before turning (and stopping)
+    /// _max_steps_ - Maximal number of steps in a straight line
+    /// _cost_ - Closure returning cost of the move from the first point to the second
+    /// (neighboring) point, `None` if the move is not possible
+    ///
+    /// # Returns
+    ///
+    /// Costs of all visited states together with predecessors on all cheapest paths
+    pub fn dijkstra_heading_steps<F>(
+        &self,
+        start: &Point,
+        end: &Point,
+        min_steps: usize,
+        max_steps: usize,
+        cost: F,
+    ) -> SearchResult<HeadingSteps>
+    where
+        F: Fn(&Point, &Point) -> Option<usize>,
+    {
+        Dijkstra::search_many_until(
+            Direction::CARDINAL.map(|direction| HeadingSteps::new(*start, direction, 0)),
+            |state| self.heading_steps_successors(state, min_steps, max_steps, &cost),
+            |state| state.point == *end && state.steps >= min_steps,
+        )
+    }
+
+    /// Get all states reachable from the state in a single move which stay in the grid.
+    ///
+    /// # Arguments
+    ///
+    /// _state_ - Current state
+    /// _forward_cost_ - Cost of one step forward
+    /// _turn_cost_ - Cost of turning by 90 degrees
+    /// _can_move_ - Closure deciding if it is possible to move from the first point to the
+    /// second (neighboring) point
+    ///
+    /// # Returns
+    ///
+    /// States reachable in a single move with the cost of the move
+    pub fn heading_successors<F>(
+        &self,
+        state: &Heading,
+        forward_cost: usize,
+        turn_cost: usize,
+        can_move: &F,
+    ) -> Vec<(Heading, usize)>
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        state
+            .successors(forward_cost, turn_cost)
+            .into_iter()
+            .filter(|(next, _)| {
+                next.point == state.point
+                    || (self.is_point_in_grid(&next.point) && can_move(&state.point, &next.point))
+            })
+            .collect()
+    }
+
+    /// Get all states reachable from the state in a single step which stay in the grid.
+    ///
+    /// # Arguments
+    ///
+    /// _state_ - Current state
+    /// _min_steps_ - Minimal number of steps in a straight line before turning
+    /// _max_steps_ - Maximal number of steps in a straight line
+    /// _cost_ - Closure returning cost of the move from the first point to the second
+    /// (neighboring) point, `None` if the move is not possible
+    ///
+    /// # Returns
+    ///
+    /// States reachable in a single step with the cost of the step
+    pub fn heading_steps_successors<F>(
+        &self,
+        state: &HeadingSteps,
+        min_steps: usize,
+        max_steps: usize,
+        cost: &F,
+    ) -> Vec<(HeadingSteps, usize)>
+    where
+        F: Fn(&Point, &Point) -> Option<usize>,
+    {
+        state
+            .successors(min_steps, max_steps)
+            .into_iter()
+            .filter(|next| self.is_point_in_grid(&next.point))
+            .filter_map(|next| cost(&state.point, &next.point).map(|cost| (next, cost)))
+            .collect()
+    }
+
+    fn cardinal_neighbors<F>(&self, point: &Point, can_move: &F) -> Vec<Point>
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        self.neighbors_if(point, &Direction::CARDINAL, |neighbor, _| {
+            can_move(point, neighbor)
+        })
+        .into_iter()
+        .map(|(neighbor, _)| neighbor)
+        .collect()
+    }
+
+    fn cardinal_successors<F>(&self, point: &Point, cost: &F) -> Vec<(Point, usize)>
+    where
+        F: Fn(&Point, &Point) -> Option<usize>,
+    {
+        self.neighbors(point, &Direction::CARDINAL)
+            .into_iter()
+            .filter_map(|(neighbor, _)| cost(point, &neighbor).map(|cost| (neighbor, cost)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn build_maze() -> Grid {
+        Grid::new_from_lines(&[
+            "S...#", //
+            ".##.#", //
+            "...#.", //
+            "#....", //
+            "...#E", //
+        ])
+        .unwrap()
+    }
+
+    fn is_open(grid: &Grid) -> impl Fn(&Point, &Point) -> bool + '_ {
+        |_, to| grid[*to] != '#'
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = build_maze();
+        let result = grid.bfs(&Point::new(0, 0), is_open(&grid));
+
+        assert_eq!(result.distance(&Point::new(0, 0)), Some(0));
+        assert_eq!(result.distance(&Point::new(3, 1)), Some(4));
+        assert_eq!(result.distance(&Point::new(4, 4)), Some(8));
+        assert_eq!(result.distance(&Point::new(4, 0)), None);
+    }
+
+    #[test]
+    fn test_bfs_all_shortest_paths() {
+        let grid = build_maze();
+        let end = Point::new(4, 4);
+        let result = grid.bfs(&Point::new(0, 0), is_open(&grid));
+
+        // Path splits only around the tile (2, 2), all other tiles are shared
+        assert_eq!(result.count_shortest_paths(&end), 2);
+        assert_eq!(result.nodes_on_shortest_paths(&[end]).len(), 10);
+    }
+
+    #[test]
+    fn test_bfs_path() {
+        let grid = build_maze();
+        let path = grid.bfs_path(&Point::new(0, 0), &Point::new(4, 4), is_open(&grid));
+
+        assert!(path.is_some());
+
+        let path = path.unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!(path.first(), Some(&Point::new(0, 0)));
+        assert_eq!(path.last(), Some(&Point::new(4, 4)));
+
+        let path = grid.bfs_path(&Point::new(0, 0), &Point::new(4, 0), is_open(&grid));
+        assert_eq!(path, None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let grid = Grid::<usize>::new_from_digit_lines(&["131", "191", "111"]).unwrap();
+        let result = grid.dijkstra(&Point::new(0, 0), |_, to| Some(grid[*to]));
+
+        assert_eq!(result.distance(&Point::new(2, 0)), Some(4));
+        assert_eq!(result.distance(&Point::new(1, 1)), Some(10));
+        assert_eq!(result.distance(&Point::new(2, 2)), Some(4));
+    }
+
+    #[test]
+    fn test_a_star() {
+        let grid = Grid::<usize>::new_from_digit_lines(&["1163", "1381", "2136", "3694"]).unwrap();
+        let result = grid.a_star(&Point::new(0, 0), &Point::new(3, 3), |_, to| {
+            Some(grid[*to])
+        });
+
+        assert!(result.is_some(), "result: {:?}", result);
+
+        let (path, cost) = result.unwrap();
+        assert_eq!(cost, 17);
+        assert_eq!(path.first(), Some(&Point::new(0, 0)));
+        assert_eq!(path.last(), Some(&Point::new(3, 3)));
+        assert_eq!(
+            path.iter().skip(1).map(|point| grid[*point]).sum::<usize>(),
+            cost
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_heading() {
+        let grid = Grid::new_from_lines(&[
+            "###############",
+            "#.......#....E#",
+            "#.#.###.#.###.#",
+            "#.....#.#...#.#",
+            "#.###.#####.#.#",
+            "#.#.#.......#.#",
+            "#.#.#####.###.#",
+            "#...........#.#",
+            "###.#.#####.#.#",
+            "#...#.....#.#.#",
+            "#.#.#.###.#.#.#",
+            "#.....#...#.#.#",
+            "#.###.#.#.#.#.#",
+            "#S..#.....#...#",
+            "###############",
+        ])
+        .unwrap();
+        let start = Heading::new(grid.get_value('S')[0], Direction::East);
+        let end = grid.get_value('E')[0];
+
+        let result = grid.dijkstra_heading(&start, &end, 1, 1000, |_, to| grid[*to] != '#');
+
+        let goals = result.closest(|state| state.point == end);
+        assert_eq!(result.distance(&goals[0]), Some(7036));
+
+        let tiles = result
+            .nodes_on_shortest_paths(&goals)
+            .into_iter()
+            .map(|state| state.point)
+            .collect::<HashSet<_>>();
+        assert_eq!(tiles.len(), 45);
+    }
+
+    #[test]
+    fn test_dijkstra_heading_steps() {
+        let grid = Grid::<usize>::new_from_digit_lines(&[
+            "2413432311323",
+            "3215453535623",
+            "3255245654254",
+            "3446585845452",
+            "4546657867536",
+            "1438598798454",
+            "4457876987766",
+            "3637877979653",
+            "4654967986887",
+            "4564679986453",
+            "1224686865563",
+            "2546548887735",
+            "4322674655533",
+        ])
+        .unwrap();
+        let start = Point::new(0, 0);
+        let end = Point::new(12, 12);
+        let cost = |_: &Point, to: &Point| Some(grid[*to]);
+
+        let result = grid.dijkstra_heading_steps(&start, &end, 1, 3, cost);
+        let goals = result.closest(|state| state.point == end);
+        assert_eq!(result.distance(&goals[0]), Some(102));
+
+        let result = grid.dijkstra_heading_steps(&start, &end, 4, 10, cost);
+        let goals = result.closest(|state| state.point == end && state.steps >= 4);
+        assert_eq!(result.distance(&goals[0]), Some(94));
+    }
+
+    #[test]
+    fn test_dijkstra_heading_free_turns() {
+        let grid = Grid::new_from_lines(&["...", "...", "..."]).unwrap();
+        let start = Heading::new(Point::new(0, 0), Direction::East);
+        let end = Point::new(2, 2);
+
+        // Turning in place costs nothing
+        let result = grid.dijkstra_heading(&start, &end, 1, 0, |_, to| grid[*to] != '#');
+
+        let goals = result.closest(|state| state.point == end);
+        assert_eq!(result.distance(&goals[0]), Some(4));
+        assert_eq!(result.nodes_on_shortest_paths(&goals).len(), 34);
+
+        // Turns in place form cycles of zero cost edges, counting has to finish anyway
+        for goal in &goals {
+            assert!(result.count_shortest_paths(goal) > 0, "goal: {:?}", goal);
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::grids::{direction::Direction, point::Point};
+
+/// Search state consisting of the position and the direction we are facing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Heading {
+    pub point: Point,
+    pub direction: Direction,
+}
+
+impl Heading {
+    pub fn new(point: Point, direction: Direction) -> Self {
+        Self { point, direction }
+    }
+
+    /// Move one step in the current direction
+    pub fn forward(&self) -> Self {
+        Self::new(self.point.neighbor(&self.direction), self.direction)
+    }
+
+    /// Turn left in place
+    pub fn turn_left(&self) -> Self {
+        Self::new(self.point, self.direction.left())
+    }
+
+    /// Turn right in place
+    pub fn turn_right(&self) -> Self {
+        Self::new(self.point, self.direction.right())
+    }
+
+    /// Get all states reachable in a single move together with their costs, i.e. one step
+    /// forward or turning left or right in place.
+    ///
+    /// # Arguments
+    ///
+    /// _forward_cost_ - Cost of one step forward
+    /// _turn_cost_ - Cost of turning by 90 degrees
+    ///
+    /// # Returns
+    ///
+    /// States reachable in a single move with the cost of the move
+    pub fn successors(&self, forward_cost: usize, turn_cost: usize) -> [(Self, usize); 3] {
+        [
+            (self.forward(), forward_cost),
+            (self.turn_left(), turn_cost),
+            (self.turn_right(), turn_cost),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moves() {
+        let heading = Heading::new(Point::new(2, 2), Direction::East);
+
+        assert_eq!(
+            heading.forward(),
+            Heading::new(Point::new(3, 2), Direction::East)
+        );
+        assert_eq!(
+            heading.turn_left(),
+            Heading::new(Point::new(2, 2), Direction::North)
+        );
+        assert_eq!(
+            heading.turn_right(),
+            Heading::new(Point::new(2, 2), Direction::South)
+        );
+    }
+
+    #[test]
+    fn test_successors() {
+        let heading = Heading::new(Point::new(2, 2), Direction::North);
+
+        assert_eq!(
+            heading.successors(1, 1000),
+            [
+                (Heading::new(Point::new(2, 1), Direction::North), 1),
+                (Heading::new(Point::new(2, 2), Direction::West), 1000),
+                (Heading::new(Point::new(2, 2), Direction::East), 1000),
+            ]
+        );
+    }
+}
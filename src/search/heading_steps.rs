@@ -0,0 +1,94 @@
+use crate::grids::{direction::Direction, point::Point};
+
+/// Search state consisting of the position, the direction we are facing and the number of steps
+/// made in this direction without turning
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HeadingSteps {
+    pub point: Point,
+    pub direction: Direction,
+    pub steps: usize,
+}
+
+impl HeadingSteps {
+    pub fn new(point: Point, direction: Direction, steps: usize) -> Self {
+        Self {
+            point,
+            direction,
+            steps,
+        }
+    }
+
+    /// Get all states reachable in a single step. It is possible to continue straight until
+    /// `max_steps` are made, turning left or right is possible only after at least `min_steps`
+    /// were made in the current direction. Turning always includes one step in the new
+    /// direction.
+    ///
+    /// # Arguments
+    ///
+    /// _min_steps_ - Minimal number of steps in a straight line before turning
+    /// _max_steps_ - Maximal number of steps in a straight line
+    ///
+    /// # Returns
+    ///
+    /// States reachable in a single step
+    pub fn successors(&self, min_steps: usize, max_steps: usize) -> Vec<Self> {
+        let mut successors = Vec::with_capacity(3);
+
+        if self.steps < max_steps {
+            successors.push(self.step(self.direction, self.steps + 1));
+        }
+
+        if self.steps >= min_steps {
+            successors.push(self.step(self.direction.left(), 1));
+            successors.push(self.step(self.direction.right(), 1));
+        }
+
+        successors
+    }
+
+    fn step(&self, direction: Direction, steps: usize) -> Self {
+        Self::new(self.point.neighbor(&direction), direction, steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_successors_straight_only() {
+        let state = HeadingSteps::new(Point::new(0, 0), Direction::East, 2);
+
+        assert_eq!(
+            state.successors(4, 10),
+            vec![HeadingSteps::new(Point::new(1, 0), Direction::East, 3)]
+        );
+    }
+
+    #[test]
+    fn test_successors_turn_only() {
+        let state = HeadingSteps::new(Point::new(0, 0), Direction::East, 3);
+
+        assert_eq!(
+            state.successors(1, 3),
+            vec![
+                HeadingSteps::new(Point::new(0, -1), Direction::North, 1),
+                HeadingSteps::new(Point::new(0, 1), Direction::South, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_successors_all() {
+        let state = HeadingSteps::new(Point::new(5, 5), Direction::South, 1);
+
+        assert_eq!(
+            state.successors(0, 3),
+            vec![
+                HeadingSteps::new(Point::new(5, 6), Direction::South, 2),
+                HeadingSteps::new(Point::new(6, 5), Direction::East, 1),
+                HeadingSteps::new(Point::new(4, 5), Direction::West, 1),
+            ]
+        );
+    }
+}
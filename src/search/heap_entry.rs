@@ -0,0 +1,30 @@
+use std::cmp::Ordering;
+
+/// Entry of the priority queue used by cost based searches. Only the priority takes part in the
+/// ordering so that nodes do not need to implement `Ord`. Ordering is reversed so that
+/// `BinaryHeap` behaves as a min-heap.
+pub(crate) struct HeapEntry<N> {
+    pub(crate) priority: usize,
+    pub(crate) cost: usize,
+    pub(crate) node: N,
+}
+
+impl<N> PartialEq for HeapEntry<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for HeapEntry<N> {}
+
+impl<N> PartialOrd for HeapEntry<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for HeapEntry<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
@@ -0,0 +1,9 @@
+pub mod a_star;
+pub mod bfs;
+pub mod dijkstra;
+pub mod grid_search;
+pub mod heading;
+pub mod heading_steps;
+pub mod search_result;
+
+mod heap_entry;
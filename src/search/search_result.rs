@@ -0,0 +1,308 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+/// Result of the graph search: distance to every reached node together with all predecessors
+/// which lie on some shortest path to that node.
+#[derive(Debug, Clone)]
+pub struct SearchResult<N> {
+    distances: HashMap<N, usize>,
+    predecessors: HashMap<N, Vec<N>>,
+    starts: HashSet<N>,
+}
+
+impl<N> Default for SearchResult<N> {
+    fn default() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            starts: HashSet::new(),
+        }
+    }
+}
+
+impl<N> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+{
+    /// Distance from the start to the node, `None` if node was not reached
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    /// Distances to all reached nodes
+    pub fn distances(&self) -> &HashMap<N, usize> {
+        &self.distances
+    }
+
+    /// Check if node was reached from the start
+    pub fn is_reachable(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    /// All predecessors of the node which lie on some shortest path to the node
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors
+            .get(node)
+            .map(|predecessors| predecessors.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Find all reached nodes satisfying the predicate which have the smallest distance
+    pub fn closest<F>(&self, func: F) -> Vec<N>
+    where
+        F: Fn(&N) -> bool,
+    {
+        let candidates = self
+            .distances
+            .iter()
+            .filter(|(node, _)| func(node))
+            .collect::<Vec<_>>();
+
+        match candidates.iter().map(|(_, distance)| **distance).min() {
+            Some(min) => candidates
+                .into_iter()
+                .filter(|(_, distance)| **distance == min)
+                .map(|(node, _)| node.clone())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Reconstruct one of the shortest paths from the start to the goal (both included)
+    pub fn path(&self, goal: &N) -> Option<Vec<N>> {
+        if !self.is_reachable(goal) {
+            return None;
+        }
+
+        let mut path = vec![goal.clone()];
+        let mut current = goal;
+
+        while let Some(previous) = self.predecessors(current).first() {
+            path.push(previous.clone());
+            current = previous;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// Collect all nodes which lie on any shortest path to any of the goals (goals included)
+    pub fn nodes_on_shortest_paths(&self, goals: &[N]) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut stack = goals
+            .iter()
+            .filter(|goal| self.is_reachable(goal))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node.clone()) {
+                stack.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+
+        nodes
+    }
+
+    /// Count number of distinct shortest paths from the start to the goal, paths going around a
+    /// cycle of zero cost edges are not counted
+    pub fn count_shortest_paths(&self, goal: &N) -> usize {
+        if !self.is_reachable(goal) {
+            return 0;
+        }
+
+        // Count paths of all predecessors before the node, edges may have zero cost so the
+        // distance alone does not give the order. Zero cost edges can form a cycle of
+        // predecessors, predecessor which is still being counted does not lie on a path.
+        let mut counts: HashMap<N, usize> = HashMap::new();
+        let mut in_progress = HashSet::new();
+        let mut stack = vec![goal.clone()];
+
+        while let Some(node) = stack.last().cloned() {
+            if counts.contains_key(&node) {
+                stack.pop();
+                continue;
+            }
+
+            // First visit of the node, count its predecessors first
+            if in_progress.insert(node.clone()) {
+                stack.extend(
+                    self.predecessors(&node)
+                        .iter()
+                        .filter(|p| !counts.contains_key(*p) && !in_progress.contains(*p))
+                        .cloned(),
+                );
+                continue;
+            }
+
+            let count = match self.predecessors(&node) {
+                [] => 1,
+                predecessors => predecessors.iter().filter_map(|p| counts.get(p)).sum(),
+            };
+
+            counts.insert(node, count);
+            stack.pop();
+        }
+
+        counts[goal]
+    }
+
+    /// Record the start node of the search
+    pub(crate) fn insert_start(&mut self, node: N) {
+        self.distances.insert(node.clone(), 0);
+        self.starts.insert(node);
+    }
+
+    /// Record a path to the `node` via `from`. Returns `true` if this is a new shortest
+    /// distance to the node, `false` if it is worse or only an equally good alternative.
+    pub(crate) fn relax(&mut self, from: &N, node: &N, distance: usize) -> bool {
+        // Start has no predecessors, zero cost edge back to it would make the paths cyclic
+        if self.starts.contains(node) {
+            return false;
+        }
+
+        match self.distances.get(node) {
+            Some(&known) if distance > known => false,
+            Some(&known) if distance == known => {
+                let predecessors = self.predecessors.entry(node.clone()).or_default();
+                if !predecessors.contains(from) {
+                    predecessors.push(from.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(node.clone(), distance);
+                self.predecessors.insert(node.clone(), vec![from.clone()]);
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Diamond graph: 0 -> 1 -> 3, 0 -> 2 -> 3, 3 -> 4
+    fn build_result() -> SearchResult<u32> {
+        let mut result = SearchResult::default();
+
+        result.insert_start(0);
+        assert!(result.relax(&0, &1, 1));
+        assert!(result.relax(&0, &2, 1));
+        assert!(result.relax(&1, &3, 2));
+        assert!(!result.relax(&2, &3, 2));
+        assert!(result.relax(&3, &4, 3));
+
+        result
+    }
+
+    #[test]
+    fn test_distance() {
+        let result = build_result();
+
+        assert_eq!(result.distance(&0), Some(0));
+        assert_eq!(result.distance(&3), Some(2));
+        assert_eq!(result.distance(&4), Some(3));
+        assert_eq!(result.distance(&5), None);
+    }
+
+    #[test]
+    fn test_relax_worse_distance() {
+        let mut result = build_result();
+
+        assert!(!result.relax(&1, &4, 10));
+        assert_eq!(result.distance(&4), Some(3));
+        assert_eq!(result.predecessors(&4), &[3]);
+    }
+
+    #[test]
+    fn test_closest() {
+        let result = build_result();
+
+        let mut closest = result.closest(|node| *node > 0);
+        closest.sort();
+        assert_eq!(closest, vec![1, 2]);
+        assert_eq!(result.closest(|node| *node >= 3), vec![3]);
+        assert!(result.closest(|node| *node > 10).is_empty());
+    }
+
+    #[test]
+    fn test_path() {
+        let result = build_result();
+
+        assert_eq!(result.path(&4), Some(vec![0, 1, 3, 4]));
+        assert_eq!(result.path(&0), Some(vec![0]));
+        assert_eq!(result.path(&5), None);
+    }
+
+    #[test]
+    fn test_nodes_on_shortest_paths() {
+        let result = build_result();
+
+        assert_eq!(
+            result.nodes_on_shortest_paths(&[4]),
+            HashSet::from([0, 1, 2, 3, 4])
+        );
+        assert_eq!(result.nodes_on_shortest_paths(&[1]), HashSet::from([0, 1]));
+        assert!(result.nodes_on_shortest_paths(&[5]).is_empty());
+    }
+
+    #[test]
+    fn test_count_shortest_paths() {
+        let result = build_result();
+
+        assert_eq!(result.count_shortest_paths(&0), 1);
+        assert_eq!(result.count_shortest_paths(&1), 1);
+        assert_eq!(result.count_shortest_paths(&3), 2);
+        assert_eq!(result.count_shortest_paths(&4), 2);
+        assert_eq!(result.count_shortest_paths(&5), 0);
+    }
+
+    #[test]
+    fn test_zero_cost_cycle_through_start() {
+        let mut result = SearchResult::default();
+
+        result.insert_start(0);
+        assert!(result.relax(&0, &1, 0));
+        assert!(!result.relax(&1, &0, 0));
+
+        assert!(result.predecessors(&0).is_empty());
+        assert_eq!(result.path(&1), Some(vec![0, 1]));
+        assert_eq!(result.count_shortest_paths(&1), 1);
+    }
+
+    #[test]
+    fn test_zero_cost_edge_between_starts() {
+        let mut result = SearchResult::default();
+
+        result.insert_start(0);
+        result.insert_start(1);
+        assert!(!result.relax(&0, &1, 0));
+        assert!(!result.relax(&1, &0, 0));
+
+        assert_eq!(result.path(&0), Some(vec![0]));
+        assert_eq!(result.path(&1), Some(vec![1]));
+    }
+
+    #[test]
+    fn test_zero_cost_cycle() {
+        let mut result = SearchResult::default();
+
+        // Nodes 1 and 2 are connected by zero cost edges in both directions
+        result.insert_start(0);
+        assert!(result.relax(&0, &1, 1));
+        assert!(result.relax(&1, &2, 1));
+        assert!(!result.relax(&2, &1, 1));
+
+        assert_eq!(result.path(&2), Some(vec![0, 1, 2]));
+        assert_eq!(result.count_shortest_paths(&2), 1);
+        assert_eq!(result.count_shortest_paths(&1), 1);
+        assert_eq!(
+            result.nodes_on_shortest_paths(&[2]),
+            HashSet::from([0, 1, 2])
+        );
+    }
+}
@@ -3,7 +3,7 @@ use advent_of_code::puzzles::puzzle_error::PuzzleError;
 pub struct Parser {}
 
 impl Parser {
-    pub fn parse_lines(lines: &[&str]) -> Result<(), PuzzleError> {
+    pub fn parse_lines(_lines: &[&str]) -> Result<(), PuzzleError> {
         Ok(())
     }
 }
@@ -1,4 +1,5 @@
 use advent_of_code::puzzles::{
+    answer::Answer,
     puzzle::{PuzzleResult, SolutionResult},
     puzzle_solver::PuzzleSolver,
 };
@@ -14,45 +15,27 @@ impl PuzzleSolver for Solver {
         "TEMPLATE_SOLVER_DESCRIPTION"
     }
 
-    fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
+    fn parse_input_file(&mut self, _lines: &[&str]) -> PuzzleResult {
         Ok(())
     }
 
     fn part_1(&self) -> SolutionResult {
-        Ok(String::from(""))
+        Ok(Answer::from(""))
     }
 
     fn part_2(&self) -> SolutionResult {
-        Ok(String::from(""))
+        Ok(Answer::from(""))
     }
 }
 
-#[cfg(test)]
-mod tests {
-
-    use std::sync::LazyLock;
-
-    use advent_of_code::puzzles::puzzle_tester::PuzzleTester;
-
-    use super::*;
-
-    const SOLUTION_1: &str = "Not solved";
-    const SOLUTION_2: &str = "Not solved";
-
-    fn get_tester() -> &'static PuzzleTester<Solver> {
-        static TESTER: LazyLock<PuzzleTester<Solver>> =
-            LazyLock::new(|| PuzzleTester::new(SOLUTION_1, SOLUTION_2));
-
-        &TESTER
-    }
-
-    // #[test]
-    // fn test_part_1() {
-    //     get_tester().test_part_1();
-    // }
-
-    // #[test]
-    // fn test_part_2() {
-    //     get_tester().test_part_2();
-    // }
-}
+// Add expected answers and examples to generate tests, e.g.
+//
+// advent_of_code::puzzle_tests!(
+//     Solver,
+//     part_1 = "123",
+//     part_2 = "456",
+//     examples = [
+//         example_1 => PuzzleExample::new("1\n2\n3").part(1, "6"),
+//     ]
+// );
+advent_of_code::puzzle_tests!(Solver);
@@ -1,3 +1,12 @@
+pub mod puzzle_macro_tests;
+pub mod puzzle_tester_tests;
 pub mod puzzle_tests;
+pub mod test_endless_solver;
+pub mod test_immutable_solver;
+pub mod test_panic_solver;
 pub mod test_puzzle_solver;
+pub mod test_quest_solver;
+pub mod test_simulation_solver;
+pub mod test_slow_solver;
+pub mod test_typed_solver;
 pub mod text_reader_tests;
@@ -0,0 +1,34 @@
+// Project resources/input.txt is empty so the solvers answer with results of an empty input
+
+mod shared_input {
+    use super::super::test_puzzle_solver::TestPuzzleSolver;
+
+    advent_of_code::puzzle_tests!(TestPuzzleSolver, part_1 = "0", part_2 = "1");
+}
+
+mod examples_only {
+    use super::super::test_quest_solver::TestQuestSolver;
+
+    advent_of_code::puzzle_tests!(
+        TestQuestSolver,
+        examples = [
+            example_sum => PuzzleExample::new("1\n2\n3").part(1, "6"),
+            example_all => PuzzleExample::new("2\n5").part(1, "7").part(2, "10").part(3, "5"),
+        ]
+    );
+}
+
+mod typed_solver {
+    use advent_of_code::puzzles::typed_puzzle_solver::TypedSolver;
+
+    use super::super::test_typed_solver::TestTypedSolver;
+
+    advent_of_code::puzzle_tests!(
+        TypedSolver<TestTypedSolver>,
+        part_1 = 0_u32,
+        part_2 = 1_u32,
+        examples = [
+            example => PuzzleExample::new("2\n3\n4").part(1, 9_u32).part(2, 24_u32),
+        ]
+    );
+}
@@ -0,0 +1,97 @@
+use std::sync::LazyLock;
+
+use advent_of_code::puzzles::{puzzle_example::PuzzleExample, puzzle_tester::PuzzleTester};
+
+use super::{
+    test_panic_solver::TestPanicSolver, test_quest_solver::TestQuestSolver,
+    test_simulation_solver::TestSimulationSolver,
+};
+
+#[test]
+fn test_tester_example() {
+    let example = PuzzleExample::new("1\n2\n3").part(1, 6_u32).part(3, "3");
+
+    PuzzleTester::<TestQuestSolver>::test_example(&example);
+}
+
+#[test]
+fn test_tester_examples() {
+    PuzzleTester::<TestQuestSolver>::test_examples(&[
+        PuzzleExample::new("2\n3").part(2, "6"),
+        PuzzleExample::new("7")
+            .part(1, "7")
+            .part(2, "7")
+            .part(3, "7"),
+    ]);
+}
+
+#[test]
+#[should_panic(
+    expected = "Example 'inline example', part 2 answer mismatch\n   answer: 6 (unsigned)\n expected: 5 (unsigned)"
+)]
+fn test_tester_example_wrong_answer() {
+    let example = PuzzleExample::new("2\n3").part(2, 5_u64);
+
+    PuzzleTester::<TestQuestSolver>::test_example(&example);
+}
+
+#[test]
+#[should_panic(expected = "Example 'two numbers', part 2 answer mismatch")]
+fn test_tester_named_example_wrong_answer() {
+    let example = PuzzleExample::new("2\n3")
+        .named("two numbers")
+        .part(2, 5_u64);
+
+    PuzzleTester::<TestQuestSolver>::test_example(&example);
+}
+
+#[test]
+#[should_panic(expected = "has no expected answer")]
+fn test_tester_example_without_answer() {
+    PuzzleTester::<TestQuestSolver>::test_example(&PuzzleExample::new("1"));
+}
+
+fn get_panic_tester() -> &'static PuzzleTester<TestPanicSolver> {
+    static TESTER: LazyLock<PuzzleTester<TestPanicSolver>> =
+        LazyLock::new(|| PuzzleTester::new("1", "2"));
+
+    &TESTER
+}
+
+#[test]
+#[should_panic(expected = "Part 1 panicked")]
+fn test_tester_panic_part_1() {
+    get_panic_tester().test_part_1();
+}
+
+#[test]
+fn test_tester_panic_part_2() {
+    // Panic of the first part must not affect the second one, run both in the same thread to
+    // make the order deterministic
+    let result = std::panic::catch_unwind(|| get_panic_tester().test_part_1());
+    assert!(result.is_err());
+
+    get_panic_tester().test_part_2();
+}
+
+#[test]
+fn test_tester_shared_state_example() {
+    // Second part continues the simulation of the first part
+    let example = PuzzleExample::new("1\n2").part(2, 45_u32);
+
+    PuzzleTester::<TestSimulationSolver>::test_example(&example);
+}
+
+#[test]
+fn test_tester_shared_state() {
+    // Project resources/input.txt is empty so the simulation stays at zero, test only that
+    // parts can be tested in any order with a shared tester
+    let tester = PuzzleTester::<TestSimulationSolver>::new(0_u32, 0_u32);
+
+    tester.test_part_2();
+    tester.test_part_1();
+
+    // Input file is parsed once and every part is solved once in order
+    assert_eq!(tester.get_solver(1).parsed(), 1);
+    assert_eq!(tester.get_solver(2).steps(), 15);
+}
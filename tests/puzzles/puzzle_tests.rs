@@ -1,6 +1,26 @@
-use advent_of_code::{env::project::Project, puzzles::puzzle::Puzzle};
+use std::time::Duration;
 
-use super::test_puzzle_solver::TestPuzzleSolver;
+use advent_of_code::{
+    env::project::Project,
+    puzzles::{
+        answer::Answer,
+        puzzle::Puzzle,
+        puzzle_answers::PuzzleAnswers,
+        puzzle_args::{PuzzleArgs, PuzzleInput},
+        puzzle_error::PuzzleError,
+        puzzle_example::PuzzleExample,
+        puzzle_report::{PuzzleReport, ReportFormat},
+        puzzle_tester::PuzzleTester,
+        typed_puzzle_solver::TypedSolver,
+    },
+};
+
+use super::{
+    test_endless_solver::TestEndlessSolver, test_immutable_solver::TestImmutableSolver,
+    test_panic_solver::TestPanicSolver, test_puzzle_solver::TestPuzzleSolver,
+    test_quest_solver::TestQuestSolver, test_simulation_solver::TestSimulationSolver,
+    test_slow_solver::TestSlowSolver, test_typed_solver::TestTypedSolver,
+};
 
 #[test]
 fn test_puzzle_no_reader() {
@@ -8,7 +28,7 @@ fn test_puzzle_no_reader() {
     let mut puzzle: Puzzle<TestPuzzleSolver> = Puzzle::new();
 
     // Solve the puzzle and check the results
-    let result = puzzle.solve();
+    let result = puzzle.solve().and_then(PuzzleReport::into_result);
 
     assert!(result.is_ok(), "Puzzle error: {:?}", result);
 }
@@ -21,7 +41,546 @@ fn test_puzzle_with_reader() {
     let mut puzzle: Puzzle<TestPuzzleSolver> = Puzzle::new_with_reader(&input_file);
 
     // Solve the puzzle and check the results
+    let result = puzzle.solve().and_then(PuzzleReport::into_result);
+
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+}
+
+#[test]
+fn test_puzzle_with_part_readers() {
+    // Create test puzzle with an input file for every part
+    let input_file = Project::new().resource_test_file("input.txt");
+
+    let mut puzzle: Puzzle<TestQuestSolver> =
+        Puzzle::new_with_part_readers(&[&input_file, &input_file, &input_file]);
+
+    // Solve the puzzle and check the results
+    let result = puzzle.solve().and_then(PuzzleReport::into_result);
+
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+}
+
+#[test]
+fn test_puzzle_with_two_part_readers() {
+    // Create test puzzle with two parts and an input file for every part
+    let input_file = Project::new().resource_test_file("input.txt");
+
+    let mut puzzle: Puzzle<TestPuzzleSolver> =
+        Puzzle::new_with_part_readers(&[&input_file, &input_file]);
+
+    // Solve the puzzle and check the results
+    let result = puzzle.solve().and_then(PuzzleReport::into_result);
+
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+}
+
+#[test]
+fn test_puzzle_with_missing_part_reader() {
+    // Create test puzzle with less input files than parts
+    let input_file = Project::new().resource_test_file("input.txt");
+
+    let mut puzzle: Puzzle<TestQuestSolver> =
+        Puzzle::new_with_part_readers(&[&input_file, &input_file]);
+
+    // Solve the puzzle and check the results
+    let result = puzzle.solve();
+
+    assert!(result.is_err(), "Puzzle result: {:?}", result);
+}
+
+#[test]
+fn test_puzzle_with_invalid_part_reader() {
+    // Create test puzzle where input file of the last part is invalid
+    let project = Project::new();
+    let input_file = project.resource_test_file("input.txt");
+    let invalid_file = project.resource_test_file("input_invalid.txt");
+
+    let mut puzzle: Puzzle<TestQuestSolver> =
+        Puzzle::new_with_part_readers(&[&input_file, &input_file, &invalid_file]);
+
+    // Solve the puzzle, failure of the last input is recorded in the report
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    let report = result.unwrap();
+    assert_eq!(report.inputs.len(), 3);
+    assert!(
+        matches!(
+            report.inputs[2].error,
+            Some(PuzzleError::InvalidContentError(_))
+        ),
+        "Input report: {:?}",
+        report.inputs[2]
+    );
+    assert_eq!(report.answer(1), Some(&Answer::from(15_u32)));
+    assert_eq!(report.answer(2), Some(&Answer::from(120_u32)));
+    assert!(report.part(3).is_none());
+
+    let result = report.into_result();
+    assert!(
+        matches!(result, Err(PuzzleError::InvalidContentError(_))),
+        "Puzzle result: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_puzzle_report() {
+    // Create test puzzle with an input file for every part
+    let input_file = Project::new().resource_test_file("input.txt");
+
+    let mut puzzle: Puzzle<TestQuestSolver> =
+        Puzzle::new_with_part_readers(&[&input_file, &input_file, &input_file]);
+    puzzle.set_silent(true);
+
+    // Solve the puzzle and check the report
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    let report = result.unwrap();
+    assert!(report.is_ok(), "Puzzle report: {:?}", report);
+    assert_eq!(report.description, "--- Test Quest ---");
+    assert_eq!(report.inputs.len(), 3);
+    assert_eq!(report.answer(1), Some(&Answer::from(15_u32)));
+    assert_eq!(report.answer(2), Some(&Answer::from(120_u32)));
+    assert_eq!(report.answer(3), Some(&Answer::from(5_u32)));
+
+    let json = report.to_json();
+    assert!(json.starts_with(r#"{"description":"--- Test Quest ---","inputs":[{"part":1,"#));
+    assert!(json.contains(r#"{"part":3,"answer":5,"expected":null,"correct":null,"#));
+
+    let csv = report.to_csv();
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 7);
+    assert_eq!(lines[0], PuzzleReport::csv_header());
+    assert!(lines[4].starts_with("--- Test Quest ---,part,1,15,"));
+}
+
+#[test]
+fn test_puzzle_run_with_args() {
+    // Solve only the second part twice without any decorations
+    let args = PuzzleArgs {
+        input: PuzzleInput::File(Project::new().resource_test_file("input.txt")),
+        part: Some(2),
+        quiet: true,
+        repeat: 2,
+        format: ReportFormat::Text,
+        help: false,
+        ..PuzzleArgs::default()
+    };
+
+    let result = Puzzle::<TestPuzzleSolver>::run_with_args(&args);
+
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+}
+
+#[test]
+fn test_puzzle_run_with_args_format() {
+    for format in [ReportFormat::Json, ReportFormat::Csv] {
+        let args = PuzzleArgs {
+            input: PuzzleInput::File(Project::new().resource_test_file("input.txt")),
+            repeat: 2,
+            format,
+            ..PuzzleArgs::default()
+        };
+
+        let result = Puzzle::<TestPuzzleSolver>::run_with_args(&args);
+
+        assert!(result.is_ok(), "Puzzle error: {:?}", result);
+    }
+}
+
+#[test]
+fn test_puzzle_run_with_args_invalid_part() {
+    let args = PuzzleArgs {
+        input: PuzzleInput::File(Project::new().resource_test_file("input.txt")),
+        part: Some(3),
+        ..PuzzleArgs::default()
+    };
+
+    let result = Puzzle::<TestPuzzleSolver>::run_with_args(&args);
+
+    assert!(
+        matches!(result, Err(PuzzleError::InvalidArgumentError(_))),
+        "Puzzle result: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_puzzle_benchmark() {
+    let input_file = Project::new().resource_test_file("input.txt");
+
+    let mut puzzle: Puzzle<TestQuestSolver> =
+        Puzzle::new_with_part_readers(&[&input_file, &input_file, &input_file]);
+
+    let result = puzzle.benchmark(5, 2);
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    let benchmark = result.unwrap();
+    let names = benchmark
+        .phases
+        .iter()
+        .map(|phase| phase.name.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        names,
+        vec![
+            "parse 1", "parse 2", "parse 3", "part 1", "part 2", "part 3"
+        ]
+    );
+    assert!(benchmark.phases.iter().all(|phase| phase.stats.runs == 5));
+    assert!(benchmark.regressions().is_empty());
+}
+
+#[test]
+fn test_puzzle_benchmark_error() {
+    let invalid_file = Project::new().resource_test_file("input_invalid.txt");
+
+    let mut puzzle: Puzzle<TestPuzzleSolver> = Puzzle::new_with_reader(&invalid_file);
+    let result = puzzle.benchmark(3, 0);
+
+    assert!(
+        matches!(result, Err(PuzzleError::ParseError(_))),
+        "Puzzle result: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_puzzle_run_with_args_bench() {
+    let args = PuzzleArgs {
+        input: PuzzleInput::File(Project::new().resource_test_file("input.txt")),
+        bench: Some(3),
+        warmup: 1,
+        ..PuzzleArgs::default()
+    };
+
+    let result = Puzzle::<TestPuzzleSolver>::run_with_args(&args);
+
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+}
+
+#[test]
+fn test_puzzle_with_answers() {
+    let input_file = Project::new().resource_test_file("input.txt");
+    let answers = PuzzleAnswers::parse(&["part_1 = 15", "part_2 = 120"]).unwrap();
+
+    let mut puzzle: Puzzle<TestQuestSolver> =
+        Puzzle::new_with_part_readers(&[&input_file, &input_file, &input_file]);
+    puzzle.set_answers(Some(answers));
+
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    let report = result.unwrap();
+    assert_eq!(report.parts[0].is_correct(), Some(true));
+    assert_eq!(report.parts[1].is_correct(), Some(true));
+    assert_eq!(report.parts[2].is_correct(), None);
+    assert!(report.is_ok(), "Puzzle report: {:?}", report);
+}
+
+#[test]
+fn test_puzzle_without_answers() {
+    let mut puzzle: Puzzle<TestPuzzleSolver> = Puzzle::new();
+    puzzle.set_silent(true);
+
+    // Answers are not checked unless they are set
+    let report = puzzle.solve().unwrap();
+    assert!(report.parts.iter().all(|part| part.is_correct().is_none()));
+}
+
+#[test]
+fn test_puzzle_with_wrong_answers() {
+    let input_file = Project::new().resource_test_file("input.txt");
+    let answers = PuzzleAnswers::parse(&["part_2 = 121"]).unwrap();
+
+    let mut puzzle: Puzzle<TestQuestSolver> =
+        Puzzle::new_with_part_readers(&[&input_file, &input_file, &input_file]);
+    puzzle.set_answers(Some(answers));
+
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    let report = result.unwrap();
+    assert_eq!(report.parts[1].is_correct(), Some(false));
+
+    let result = report.into_result();
+    assert!(
+        matches!(result, Err(PuzzleError::GenericError(_))),
+        "Puzzle result: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_puzzle_with_typed_solver() {
+    let input_file = Project::new().resource_test_file("input.txt");
+
+    let mut puzzle: Puzzle<TypedSolver<TestTypedSolver>> = Puzzle::new_with_reader(&input_file);
+    puzzle.set_silent(true);
+
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    let report = result.unwrap();
+    assert!(report.is_ok(), "Puzzle report: {:?}", report);
+    assert_eq!(report.description, "--- Test Typed Puzzle ---");
+    assert_eq!(report.answer(1), Some(&Answer::from(15_u32)));
+    assert_eq!(report.answer(2), Some(&Answer::from(120_u32)));
+}
+
+#[test]
+fn test_puzzle_with_shared_state() {
+    let input_file = Project::new().resource_test_file("input.txt");
+
+    let mut puzzle: Puzzle<TestSimulationSolver> = Puzzle::new_with_reader(&input_file);
+    puzzle.set_silent(true);
+
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    let report = result.unwrap();
+    assert_eq!(report.answer(1), Some(&Answer::from(150_u32)));
+    assert_eq!(report.answer(2), Some(&Answer::from(225_u32)));
+}
+
+#[test]
+fn test_puzzle_with_shared_state_single_part() {
+    let input_file = Project::new().resource_test_file("input.txt");
+
+    // Second part continues from the first part even if it is not selected
+    let mut puzzle: Puzzle<TestSimulationSolver> = Puzzle::new_with_reader(&input_file);
+    puzzle.set_silent(true);
+    puzzle.set_part(Some(2));
+
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    let report = result.unwrap();
+    assert!(report.part(1).is_none());
+    assert_eq!(report.answer(2), Some(&Answer::from(225_u32)));
+}
+
+#[test]
+fn test_puzzle_parallel() {
+    let input_file = Project::new().resource_test_file("input.txt");
+
+    let mut puzzle: Puzzle<TestPuzzleSolver> = Puzzle::new_with_reader(&input_file);
+    puzzle.set_silent(true);
+    puzzle.set_parallel(true);
+
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    let report = result.unwrap();
+    assert!(report.is_ok(), "Puzzle report: {:?}", report);
+    assert_eq!(report.answer(1), Some(&Answer::from(15_u32)));
+    assert_eq!(report.answer(2), Some(&Answer::from(120_u32)));
+}
+
+#[test]
+fn test_puzzle_parallel_with_shared_state() {
+    let input_file = Project::new().resource_test_file("input.txt");
+
+    // Parts sharing state are always solved sequentially
+    let mut puzzle: Puzzle<TestSimulationSolver> = Puzzle::new_with_reader(&input_file);
+    puzzle.set_silent(true);
+    puzzle.set_parallel(true);
+
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    let report = result.unwrap();
+    assert_eq!(report.answer(1), Some(&Answer::from(150_u32)));
+    assert_eq!(report.answer(2), Some(&Answer::from(225_u32)));
+}
+
+#[test]
+fn test_puzzle_run_parallel_with_args() {
+    let args = PuzzleArgs {
+        input: PuzzleInput::File(Project::new().resource_test_file("input.txt")),
+        ..PuzzleArgs::default()
+    };
+
+    let result = Puzzle::<TestPuzzleSolver>::run_parallel_with_args(&args);
+
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+}
+
+#[test]
+fn test_puzzle_timeout() {
+    let mut puzzle: Puzzle<TestEndlessSolver> = Puzzle::new();
+    puzzle.set_silent(true);
+    puzzle.set_timeout(Some(Duration::from_millis(50)));
+
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    // Part which exceeds the time limit fails, remaining parts are still solved
+    let report = result.unwrap();
+    assert!(
+        matches!(
+            report.part(1).and_then(|part| part.error.as_ref()),
+            Some(PuzzleError::TimeoutError(timeout)) if *timeout == Duration::from_millis(50)
+        ),
+        "Puzzle report: {:?}",
+        report
+    );
+    assert_eq!(report.answer(2), Some(&Answer::from(2_u32)));
+}
+
+#[test]
+fn test_puzzle_timeout_without_polling() {
+    let mut puzzle: Puzzle<TestSlowSolver> = Puzzle::new();
+    puzzle.set_silent(true);
+    puzzle.set_timeout(Some(Duration::from_millis(20)));
+
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    // Part which returns late is reported as timed out although it has an answer
+    let report = result.unwrap();
+    assert!(
+        matches!(
+            report.part(1).and_then(|part| part.error.as_ref()),
+            Some(PuzzleError::TimeoutError(timeout)) if *timeout == Duration::from_millis(20)
+        ),
+        "Puzzle report: {:?}",
+        report
+    );
+    assert!(report.answer(1).is_none());
+    assert_eq!(report.answer(2), Some(&Answer::from(2_u32)));
+}
+
+#[test]
+fn test_puzzle_parallel_timeout() {
+    let mut puzzle: Puzzle<TestEndlessSolver> = Puzzle::new();
+    puzzle.set_silent(true);
+    puzzle.set_parallel(true);
+    puzzle.set_timeout(Some(Duration::from_millis(50)));
+
     let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    let report = result.unwrap();
+    assert!(
+        matches!(
+            report.part(1).and_then(|part| part.error.as_ref()),
+            Some(PuzzleError::TimeoutError(_))
+        ),
+        "Puzzle report: {:?}",
+        report
+    );
+    assert_eq!(report.answer(2), Some(&Answer::from(2_u32)));
+}
+
+#[test]
+fn test_puzzle_panic() {
+    let mut puzzle: Puzzle<TestPanicSolver> = Puzzle::new();
+    puzzle.set_silent(true);
 
+    let result = puzzle.solve();
     assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    // Panic of the part is reported as error, remaining parts are still solved
+    let report = result.unwrap();
+    assert!(
+        matches!(
+            report.part(1).and_then(|part| part.error.as_ref()),
+            Some(PuzzleError::PanicError(message, Some(location)))
+                if message == "Part 1 panicked" && location.contains("test_panic_solver.rs")
+        ),
+        "Puzzle report: {:?}",
+        report
+    );
+    assert_eq!(report.answer(2), Some(&Answer::from(2_u32)));
+}
+
+#[test]
+fn test_puzzle_parallel_panic() {
+    let mut puzzle: Puzzle<TestPanicSolver> = Puzzle::new();
+    puzzle.set_silent(true);
+    puzzle.set_parallel(true);
+
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    let report = result.unwrap();
+    assert!(
+        matches!(
+            report.part(1).and_then(|part| part.error.as_ref()),
+            Some(PuzzleError::PanicError(_, _))
+        ),
+        "Puzzle report: {:?}",
+        report
+    );
+    assert_eq!(report.answer(2), Some(&Answer::from(2_u32)));
+}
+
+#[test]
+fn test_puzzle_parse_error() {
+    let invalid_file = Project::new().resource_test_file("input_invalid.txt");
+
+    let mut puzzle: Puzzle<TestPuzzleSolver> = Puzzle::new_with_reader(&invalid_file);
+    puzzle.set_silent(true);
+
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    // Parse error knows the input file and the position of the error
+    let report = result.unwrap();
+    match &report.inputs[0].error {
+        Some(PuzzleError::ParseError(err)) => {
+            assert_eq!(err.file, Some(invalid_file.to_string_lossy().to_string()));
+            assert_eq!(err.line, 3);
+            assert_eq!(err.columns, 0..4);
+            assert_eq!(err.content, "uups");
+        }
+        err => panic!("Unexpected error: {:?}", err),
+    }
+}
+
+#[test]
+fn test_puzzle_part_readers_with_shared_state() {
+    let input_file = Project::new().resource_test_file("input.txt");
+
+    // Previous part is solved with its own input file before the selected part
+    let mut puzzle: Puzzle<TestSimulationSolver> =
+        Puzzle::new_with_part_readers(&[&input_file, &input_file]);
+    puzzle.set_silent(true);
+    puzzle.set_part(Some(2));
+
+    let result = puzzle.solve();
+    assert!(result.is_ok(), "Puzzle error: {:?}", result);
+
+    let report = result.unwrap();
+    assert!(report.is_ok(), "Puzzle report: {:?}", report);
+    assert!(report.part(1).is_none());
+    assert_eq!(report.answer(2), Some(&Answer::from(225_u32)));
+
+    // Same answer as if the puzzle shares single input file
+    let mut puzzle: Puzzle<TestSimulationSolver> = Puzzle::new_with_reader(&input_file);
+    puzzle.set_silent(true);
+    puzzle.set_part(Some(2));
+
+    let report = puzzle.solve().unwrap();
+    assert_eq!(report.answer(2), Some(&Answer::from(225_u32)));
+}
+
+#[test]
+fn test_puzzle_mutable_part_without_shared_state() {
+    let mut puzzle: Puzzle<TestImmutableSolver> = Puzzle::new();
+    puzzle.set_silent(true);
+
+    // Solver which does not share state is always solved by the immutable parts
+    let report = puzzle.solve().unwrap();
+    assert_eq!(report.answer(1), Some(&Answer::from(1_u32)));
+
+    puzzle.set_parallel(true);
+    let report = puzzle.solve().unwrap();
+    assert_eq!(report.answer(1), Some(&Answer::from(1_u32)));
+
+    PuzzleTester::<TestImmutableSolver>::test_example(&PuzzleExample::new("").part(1, 1_u32));
 }
@@ -0,0 +1,36 @@
+use advent_of_code::puzzles::{
+    cancellation_token::CancellationToken,
+    puzzle::{PuzzleResult, SolutionResult},
+    puzzle_solver::PuzzleSolver,
+};
+
+/// Implements puzzle solver whose first part never finishes unless it is cancelled for test
+/// purposes
+pub struct TestEndlessSolver {}
+
+impl PuzzleSolver for TestEndlessSolver {
+    fn new() -> Self {
+        TestEndlessSolver {}
+    }
+
+    fn get_description(&self) -> &str {
+        "--- Test Endless ---"
+    }
+
+    fn parse_input_file(&mut self, _lines: &[&str]) -> PuzzleResult {
+        Ok(())
+    }
+
+    fn part_1(&self) -> SolutionResult {
+        let token = CancellationToken::current();
+
+        loop {
+            token.check()?;
+            std::thread::yield_now();
+        }
+    }
+
+    fn part_2(&self) -> SolutionResult {
+        Ok(2_u32.into())
+    }
+}
@@ -0,0 +1,34 @@
+use advent_of_code::puzzles::{
+    puzzle::{PuzzleResult, SolutionResult},
+    puzzle_solver::PuzzleSolver,
+};
+
+/// Implements puzzle solver which overrides the mutable part but does not share state between
+/// the parts for test purposes, the mutable part must never be called
+pub struct TestImmutableSolver {}
+
+impl PuzzleSolver for TestImmutableSolver {
+    fn new() -> Self {
+        TestImmutableSolver {}
+    }
+
+    fn get_description(&self) -> &str {
+        "--- Test Immutable ---"
+    }
+
+    fn parse_input_file(&mut self, _lines: &[&str]) -> PuzzleResult {
+        Ok(())
+    }
+
+    fn part_1(&self) -> SolutionResult {
+        Ok(1_u32.into())
+    }
+
+    fn part_1_mut(&mut self) -> SolutionResult {
+        Ok(100_u32.into())
+    }
+
+    fn part_2(&self) -> SolutionResult {
+        Ok(2_u32.into())
+    }
+}
@@ -0,0 +1,29 @@
+use advent_of_code::puzzles::{
+    puzzle::{PuzzleResult, SolutionResult},
+    puzzle_solver::PuzzleSolver,
+};
+
+/// Implements puzzle solver whose first part panics for test purposes
+pub struct TestPanicSolver {}
+
+impl PuzzleSolver for TestPanicSolver {
+    fn new() -> Self {
+        TestPanicSolver {}
+    }
+
+    fn get_description(&self) -> &str {
+        "--- Test Panic ---"
+    }
+
+    fn parse_input_file(&mut self, _lines: &[&str]) -> PuzzleResult {
+        Ok(())
+    }
+
+    fn part_1(&self) -> SolutionResult {
+        panic!("Part 1 panicked");
+    }
+
+    fn part_2(&self) -> SolutionResult {
+        Ok(2_u32.into())
+    }
+}
@@ -1,6 +1,6 @@
 use advent_of_code::puzzles::{
+    input_line::InputLine,
     puzzle::{PuzzleResult, SolutionResult},
-    puzzle_error::PuzzleError,
     puzzle_solver::PuzzleSolver,
 };
 
@@ -21,26 +21,18 @@ impl PuzzleSolver for TestPuzzleSolver {
     }
 
     fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
-        let mut content = Vec::new();
-
-        for line in lines {
-            let number = line.parse::<u32>().map_err(|err| {
-                PuzzleError::InvalidContentError(format!(
-                    "Failed to convert '{line}' to u32 with error '{err}'"
-                ))
-            })?;
-
-            content.push(number);
-        }
+        self.content = InputLine::iter(lines)
+            .map(|line| line.parse::<u32>())
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(())
     }
 
     fn part_1(&self) -> SolutionResult {
-        Ok(self.content.iter().sum::<u32>().to_string())
+        Ok(self.content.iter().sum::<u32>().into())
     }
 
     fn part_2(&self) -> SolutionResult {
-        Ok(self.content.iter().product::<u32>().to_string())
+        Ok(self.content.iter().product::<u32>().into())
     }
 }
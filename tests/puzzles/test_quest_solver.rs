@@ -0,0 +1,69 @@
+use advent_of_code::puzzles::{
+    puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::PuzzleError,
+    puzzle_solver::PuzzleSolver,
+};
+
+/// Implements puzzle solver with three parts for test purposes
+pub struct TestQuestSolver {
+    content: Vec<u32>,
+    parsed_part: usize,
+}
+
+impl TestQuestSolver {
+    fn check_parsed_part(&self, part: usize) -> PuzzleResult {
+        match self.parsed_part == part {
+            true => Ok(()),
+            false => Err(PuzzleError::GenericError(format!(
+                "Part {part} solved with input of part {}",
+                self.parsed_part
+            ))),
+        }
+    }
+}
+
+impl PuzzleSolver for TestQuestSolver {
+    fn new() -> Self {
+        TestQuestSolver {
+            content: Vec::new(),
+            parsed_part: 0,
+        }
+    }
+
+    fn get_description(&self) -> &str {
+        "--- Test Quest ---"
+    }
+
+    fn get_parts_count(&self) -> usize {
+        3
+    }
+
+    fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
+        self.content = lines
+            .iter()
+            .map(|line| line.parse::<u32>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(())
+    }
+
+    fn parse_input_part(&mut self, part: usize, lines: &[&str]) -> PuzzleResult {
+        self.parsed_part = part;
+        self.parse_input_file(lines)
+    }
+
+    fn part_1(&self) -> SolutionResult {
+        self.check_parsed_part(1)?;
+        Ok(self.content.iter().sum::<u32>().into())
+    }
+
+    fn part_2(&self) -> SolutionResult {
+        self.check_parsed_part(2)?;
+        Ok(self.content.iter().product::<u32>().into())
+    }
+
+    fn part_3(&self) -> SolutionResult {
+        self.check_parsed_part(3)?;
+        Ok((*self.content.iter().max().unwrap_or(&0)).into())
+    }
+}
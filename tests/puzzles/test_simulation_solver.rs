@@ -0,0 +1,75 @@
+use advent_of_code::puzzles::{
+    puzzle::{PuzzleResult, SolutionResult},
+    puzzle_error::ErrorContext,
+    puzzle_solver::PuzzleSolver,
+};
+
+/// Implements puzzle solver whose second part continues the simulation of the first part for
+/// test purposes. Every step of the simulation adds all numbers to the total.
+pub struct TestSimulationSolver {
+    content: Vec<u32>,
+    total: u32,
+    steps: usize,
+    parsed: usize,
+}
+
+impl TestSimulationSolver {
+    /// Get number of simulated steps
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Get number of parsed input files
+    pub fn parsed(&self) -> usize {
+        self.parsed
+    }
+
+    fn simulate(&mut self, steps: usize) -> u32 {
+        for _ in 0..steps {
+            self.total += self.content.iter().sum::<u32>();
+        }
+
+        self.steps += steps;
+        self.total
+    }
+}
+
+impl PuzzleSolver for TestSimulationSolver {
+    fn new() -> Self {
+        TestSimulationSolver {
+            content: Vec::new(),
+            total: 0,
+            steps: 0,
+            parsed: 0,
+        }
+    }
+
+    fn get_description(&self) -> &str {
+        "--- Test Simulation ---"
+    }
+
+    fn parse_input_file(&mut self, lines: &[&str]) -> PuzzleResult {
+        self.content = lines
+            .iter()
+            .map(|line| {
+                line.parse::<u32>()
+                    .with_context(|| format!("Failed to convert '{line}' to u32"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.parsed += 1;
+
+        Ok(())
+    }
+
+    fn parts_share_state(&self) -> bool {
+        true
+    }
+
+    fn part_1_mut(&mut self) -> SolutionResult {
+        Ok(self.simulate(10).into())
+    }
+
+    fn part_2_mut(&mut self) -> SolutionResult {
+        Ok(self.simulate(5).into())
+    }
+}
@@ -0,0 +1,33 @@
+use std::{thread::sleep, time::Duration};
+
+use advent_of_code::puzzles::{
+    puzzle::{PuzzleResult, SolutionResult},
+    puzzle_solver::PuzzleSolver,
+};
+
+/// Implements puzzle solver whose first part takes long time and does not poll the cancellation
+/// token for test purposes
+pub struct TestSlowSolver {}
+
+impl PuzzleSolver for TestSlowSolver {
+    fn new() -> Self {
+        TestSlowSolver {}
+    }
+
+    fn get_description(&self) -> &str {
+        "--- Test Slow ---"
+    }
+
+    fn parse_input_file(&mut self, _lines: &[&str]) -> PuzzleResult {
+        Ok(())
+    }
+
+    fn part_1(&self) -> SolutionResult {
+        sleep(Duration::from_millis(100));
+        Ok(1_u32.into())
+    }
+
+    fn part_2(&self) -> SolutionResult {
+        Ok(2_u32.into())
+    }
+}
@@ -0,0 +1,26 @@
+use advent_of_code::puzzles::{
+    puzzle::SolutionResult, puzzle_error::PuzzleError, typed_puzzle_solver::TypedPuzzleSolver,
+};
+
+/// Implements typed puzzle solver for test purposes
+pub struct TestTypedSolver {}
+
+impl TypedPuzzleSolver for TestTypedSolver {
+    type Input = Vec<u32>;
+
+    fn get_description() -> &'static str {
+        "--- Test Typed Puzzle ---"
+    }
+
+    fn parse_input(lines: &[&str]) -> Result<Self::Input, PuzzleError> {
+        lines.iter().map(|line| Ok(line.parse::<u32>()?)).collect()
+    }
+
+    fn part_1(input: &Self::Input) -> SolutionResult {
+        Ok(input.iter().sum::<u32>().into())
+    }
+
+    fn part_2(input: &Self::Input) -> SolutionResult {
+        Ok(input.iter().product::<u32>().into())
+    }
+}
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{io::Cursor, path::Path};
 
 use advent_of_code::{
     env::project::Project,
@@ -45,3 +45,14 @@ fn test_text_reader_empty_file() {
         result
     );
 }
+
+#[test]
+fn test_text_reader_from_buffer() {
+    let reader = TextReader::new_stdin();
+
+    let result = reader.read_lines_from(Cursor::new("1\n2\n3"));
+
+    assert!(result.is_ok(), "result: {:?}", result);
+    assert_eq!(result.unwrap(), vec!["1", "2", "3"]);
+    assert_eq!(reader.get_file_path_as_string(), "<stdin>");
+}